@@ -23,8 +23,12 @@ THE SOFTWARE.
 //! Corrections for aberration
 
 use angle;
+use angle::Radians;
 use time;
 use coords;
+use planet;
+use precess;
+use sun;
 
 /**
 Computes solar aberration in ecliptic longitude
@@ -44,6 +48,47 @@ pub fn sol_aberr(R: f64) -> f64
     -angle::deg_frm_dms(0, 0, 20.4898).to_radians() / R
 }
 
+/**
+Computes stellar aberration in ecliptic coordinates
+
+# Returns
+
+`(abrr_in_long, abrr_in_lat)`
+
+* `abrr_in_long`: Aberration in ecliptic longitude *| in radians*
+* `abrr_in_lat` : Aberration in ecliptic latitude *| in radians*
+
+This is Meeus's formula 23.2, which takes the motion of the Earth from
+the Sun's true longitude and from the eccentricity and perihelion of
+the Earth's orbit. It applies to a planet too, once its position has
+been corrected for light-time.
+
+# Arguments
+
+* `ecl_point`: Ecliptic coordinates of the star *| in radians*,
+               referred to the mean equinox of the date
+* `JD`       : Julian (Ephemeris) day
+**/
+pub fn stell_aberr_in_ecl_coords(ecl_point: &coords::EclPoint, JD: f64) -> (f64, f64)
+{
+    let k = angle::deg_frm_dms(0, 0, 20.49552).to_radians();
+    let sun_long = sun::geocent_ecl_pos(JD).0.long;
+    let (_, _, e, _, _, pi, _, _) = planet::orb_elements(&planet::Planet::Earth, JD);
+
+    let (long, lat) = (ecl_point.long, ecl_point.lat);
+
+    let delta_long = (
+        -k * (sun_long - long).cos()
+      + e * k * (pi - long).cos()
+    ) / lat.cos();
+    let delta_lat = -k * lat.sin() * (
+        (sun_long - long).sin()
+      - e * (pi - long).sin()
+    );
+
+    (delta_long, delta_lat)
+}
+
 /**
 Computes stellar aberration in equatorial coordinates
 
@@ -344,3 +389,57 @@ pub fn stell_aberr_in_eq_coords(stell_eq_point: &coords::EqPoint,
 
     (delta_asc, delta_dec)
 }
+
+/**
+Computes stellar aberration in equatorial coordinates from the velocity
+of the Earth
+
+# Returns
+
+`(abrr_in_asc, abrr_in_dec)`
+
+* `abrr_in_asc`: Aberration in right ascension *| in radians*
+* `abrr_in_dec`: Aberration in declination *| in radians*
+
+Unlike [stell_aberr_in_eq_coords()](./fn.stell_aberr_in_eq_coords.html),
+which uses the series of Ron and Vondrák, this function takes the
+Earth's barycentric velocity from
+[planet::barycent_rect_vel()](../planet/fn.barycent_rect_vel.html),
+referred to the ecliptic of J2000.0, and turns it to the mean equator
+and equinox of the date before applying it.
+
+# Arguments
+
+* `stell_eq_point`: Equatorial coordinates of the star *| in radians*,
+                    referred to the mean equator and equinox of the
+                    date
+* `JD`            : Julian (Ephemeris) day
+**/
+pub fn stell_aberr_frm_earth_vel(stell_eq_point: &coords::EqPoint, JD: f64) -> (f64, f64)
+{
+    let (x, y, z) = planet::barycent_rect_vel(&planet::Planet::Earth, JD);
+
+    // From the ecliptic to the equator of J2000.0
+    let oblq_eclip = 23.4392911_f64.to_radians();
+    let y_eq = y*oblq_eclip.cos() - z*oblq_eclip.sin();
+    let z_eq = y*oblq_eclip.sin() + z*oblq_eclip.cos();
+
+    // and on to the mean equator and equinox of the date
+    let v = (x*x + y_eq*y_eq + z_eq*z_eq).sqrt();
+    let (asc_v, dec_v) = precess::precess_eq_coords(
+        Radians(y_eq.atan2(x)), Radians((z_eq/v).asin()), 2451545.0, JD
+    );
+    let x = v * dec_v.cos() * asc_v.cos();
+    let y_eq = v * dec_v.cos() * asc_v.sin();
+    let z_eq = v * dec_v.sin();
+
+    // Speed of light *| in AU per day*
+    let c = 173.1446327;
+
+    let (asc, dec) = (stell_eq_point.asc, stell_eq_point.dec);
+
+    let delta_asc = (y_eq*asc.cos() - x*asc.sin()) / (c*dec.cos());
+    let delta_dec = -((x*asc.cos() + y_eq*asc.sin())*dec.sin() - z_eq*dec.cos()) / c;
+
+    (delta_asc, delta_dec)
+}
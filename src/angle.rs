@@ -22,6 +22,7 @@ THE SOFTWARE.
 
 //! Angles for astronomy
 
+use error::Error;
 use std;
 
 pub const TWO_PI: f64 = 2.0 * std::f64::consts::PI;
@@ -40,8 +41,9 @@ Angle 1 may be right ascension or longitude.
 Angle 2 may be declination or latitude.
 **/
 #[inline]
-pub fn anglr_sepr(p1a1: f64, p1a2: f64, p2a1: f64, p2a2: f64) -> f64
+pub fn anglr_sepr<A: Angle, B: Angle, C: Angle, D: Angle>(p1a1: A, p1a2: B, p2a1: C, p2a2: D) -> f64
 {
+    let (p1a1, p1a2, p2a1, p2a2) = (p1a1.rad(), p1a2.rad(), p2a1.rad(), p2a2.rad());
     (
           p1a2.sin() * p2a2.sin()
         + p1a2.cos() * p2a2.cos() * (p1a1 - p2a1).cos()
@@ -180,3 +182,233 @@ pub fn limit_to_two_PI(angl: f64) -> f64
     if limited_angl < 0.0 { limited_angl + TWO_PI }
     else                  { limited_angl }
 }
+
+/// Represents an angle that can be expressed in radians
+///
+/// Plain `f64` values are taken to be in radians, as they are
+/// everywhere else in this crate.
+pub trait Angle: Copy {
+    /// Returns the angle *| in radians*
+    fn rad(self) -> f64;
+
+    /// Returns the angle *| in degrees*
+    #[inline]
+    fn deg(self) -> f64 {
+        self.rad().to_degrees()
+    }
+}
+
+/// Represents an angle in radians
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+/// Represents an angle in degrees
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// Represents an angle in hours, such as a right ascension or an
+/// hour angle
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct HourAngle(pub f64);
+
+impl Angle for f64 {
+    #[inline]
+    fn rad(self) -> f64 { self }
+}
+
+impl Angle for Radians {
+    #[inline]
+    fn rad(self) -> f64 { self.0 }
+}
+
+impl Angle for Degrees {
+    #[inline]
+    fn rad(self) -> f64 { self.0.to_radians() }
+
+    #[inline]
+    fn deg(self) -> f64 { self.0 }
+}
+
+impl Angle for HourAngle {
+    #[inline]
+    fn rad(self) -> f64 { (15.0 * self.0).to_radians() }
+
+    #[inline]
+    fn deg(self) -> f64 { 15.0 * self.0 }
+}
+
+impl From<Degrees> for Radians {
+    fn from(angl: Degrees) -> Radians { Radians(angl.rad()) }
+}
+
+impl From<HourAngle> for Radians {
+    fn from(angl: HourAngle) -> Radians { Radians(angl.rad()) }
+}
+
+impl From<Radians> for Degrees {
+    fn from(angl: Radians) -> Degrees { Degrees(angl.deg()) }
+}
+
+impl From<HourAngle> for Degrees {
+    fn from(angl: HourAngle) -> Degrees { Degrees(angl.deg()) }
+}
+
+impl From<Radians> for HourAngle {
+    fn from(angl: Radians) -> HourAngle { HourAngle(angl.deg() / 15.0) }
+}
+
+impl From<Degrees> for HourAngle {
+    fn from(angl: Degrees) -> HourAngle { HourAngle(angl.0 / 15.0) }
+}
+
+impl Radians {
+    /// Returns the equivalent angle in the [0, 2π] radian range
+    #[inline]
+    pub fn limit_to_two_PI(self) -> Radians {
+        Radians(limit_to_two_PI(self.0))
+    }
+}
+
+impl Degrees {
+    /// Creates an angle from degrees, arcminutes and arcseconds
+    #[inline]
+    pub fn from_dms(deg: i64, min: i64, sec: f64) -> Degrees {
+        Degrees(deg_frm_dms(deg, min, sec))
+    }
+
+    /// Returns the angle as `(deg, min, sec)`
+    #[inline]
+    pub fn dms(self) -> (i64, i64, f64) {
+        dms_frm_deg(self.0)
+    }
+
+    /// Returns the equivalent angle in the [0, 360] degree range
+    #[inline]
+    pub fn limit_to_360(self) -> Degrees {
+        Degrees(limit_to_360(self.0))
+    }
+}
+
+impl HourAngle {
+    /// Creates an angle from hours, minutes and seconds
+    #[inline]
+    pub fn from_hms(hour: i64, min: i64, sec: f64) -> HourAngle {
+        HourAngle(deg_frm_hms(hour, min, sec) / 15.0)
+    }
+
+    /// Returns the angle as `(hour, min, sec)`
+    #[inline]
+    pub fn hms(self) -> (i64, i64, f64) {
+        hms_frm_deg(self.deg())
+    }
+
+    /// Returns the equivalent angle in the [0, 24] hour range
+    #[inline]
+    pub fn limit_to_24(self) -> HourAngle {
+        HourAngle(limit_to_360(self.deg()) / 15.0)
+    }
+}
+
+/// Writes the sexagesimal components of `(a, b, c)`, carrying
+/// seconds that round up to 60
+fn fmt_sexagesimal(
+    f     : &mut std::fmt::Formatter,
+    neg   : bool,
+    (mut a, mut b, c): (i64, i64, f64),
+    units : [&str; 3]
+) -> std::fmt::Result {
+
+    let prec = f.precision().unwrap_or(2);
+    let mut c = format!("{:.*}", prec, c);
+
+    if c.parse::<f64>().map(|s| s >= 60.0).unwrap_or(false) {
+        c = format!("{:.*}", prec, 0.0);
+        b += 1;
+        if b == 60 {
+            b = 0;
+            a += 1;
+        }
+    }
+
+    write!(
+        f, "{}{}{}{}{}{}{}",
+        if neg { "-" } else { "" }, a, units[0], b, units[1], c, units[2]
+    )
+
+}
+
+/// Parses up to three sexagesimal components separated by
+/// whitespace, `:` or any of the `units`. All but the last
+/// component must be integers.
+fn parse_sexagesimal(s: &str, units: &[char]) -> Result<f64, Error> {
+
+    let s = s.trim();
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None    => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let parts: Vec<&str> = s
+        .split(|c: char| c.is_whitespace() || c == ':' || units.contains(&c))
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    if parts.is_empty() || parts.len() > 3 {
+        return Err(Error::InvalidAngle);
+    }
+
+    let mut value = 0.0;
+    let mut scale = 1.0;
+    for (i, part) in parts.iter().enumerate() {
+        let x =
+            if i + 1 == parts.len() {
+                part.parse::<f64>().map_err(|_| Error::InvalidAngle)?
+            } else {
+                part.parse::<u64>().map_err(|_| Error::InvalidAngle)? as f64
+            };
+        if x < 0.0 || (i > 0 && x >= 60.0) {
+            return Err(Error::InvalidAngle);
+        }
+        value += x / scale;
+        scale *= 60.0;
+    }
+
+    Ok(if neg { -value } else { value })
+
+}
+
+/// Formats the angle as `12°34'56.78"`. The precision of the
+/// arcseconds defaults to two decimals.
+impl std::fmt::Display for Degrees {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt_sexagesimal(f, self.0 < 0.0, dms_frm_deg(self.0.abs()), ["°", "'", "\""])
+    }
+}
+
+/// Formats the angle as `12h34m56.78s`. The precision of the
+/// seconds defaults to two decimals.
+impl std::fmt::Display for HourAngle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt_sexagesimal(f, self.0 < 0.0, hms_frm_deg(self.deg().abs()), ["h", "m", "s"])
+    }
+}
+
+/// Parses angles such as `-12°34'56.78"`, `12 34 56.78`,
+/// `12:34:56.78` or `12.5`
+impl std::str::FromStr for Degrees {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Degrees, Error> {
+        parse_sexagesimal(s, &['°', '\'', '"', '′', '″', 'd']).map(Degrees)
+    }
+}
+
+/// Parses angles such as `12h34m56.78s`, `12 34 56.78`,
+/// `12:34:56.78` or `12.5`
+impl std::str::FromStr for HourAngle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<HourAngle, Error> {
+        parse_sexagesimal(s, &['h', 'm', 's']).map(HourAngle)
+    }
+}
@@ -0,0 +1,180 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Conjunctions and closest approaches of two bodies
+
+use angle;
+use angle::Radians;
+use coords;
+use ecliptic;
+use error::Error;
+use nutation;
+use planet;
+use search;
+use std;
+use transit;
+use transit::Body;
+
+/// Represents a kind of conjunction
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ConjunctType {
+    /// The two bodies are at their least angular separation
+    ClosestApproach,
+    /// The two bodies have the same apparent right ascension
+    InAsc,
+    /// The two bodies have the same apparent ecliptic longitude
+    InLong
+}
+
+/// Represents a conjunction of two bodies
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Conjunction {
+    /// Type of the conjunction
+    pub conj_type: ConjunctType,
+    /// Julian (Ephemeris) day of the conjunction
+    pub JD: f64,
+    /// Angular separation of the two bodies at the time *| in radians*
+    pub sepr: f64
+}
+
+/**
+Finds the conjunctions of two bodies in a time range
+
+# Returns
+
+* `conjunctions`: Every closest approach, conjunction in right
+                  ascension and conjunction in ecliptic longitude of
+                  the two bodies between `JD_start` and `JD_end`,
+                  sorted by time
+
+Positions are the apparent geocentric places from
+[transit::apprnt_eq_coords()](../transit/fn.apprnt_eq_coords.html),
+referred to the true equator, ecliptic and equinox of the date. The range is stepped through a day at a time (six hours at a
+time if the Moon is one of the bodies), and each event found is then
+refined to well under a second.
+
+# Arguments
+
+* `body1`   : The first [Body](../transit/enum.Body.html)
+* `body2`   : The second [Body](../transit/enum.Body.html)
+* `JD_start`: Julian (Ephemeris) day at which to start searching
+* `JD_end`  : Julian (Ephemeris) day at which to stop searching
+**/
+pub fn conjunctions(body1: &Body, body2: &Body, JD_start: f64, JD_end: f64) -> Result<Vec<Conjunction>, Error> {
+
+    for body in &[body1, body2] {
+        if let Body::Planet(planet::Planet::Earth) = **body {
+            return Err(Error::InvalidPlanet {
+                planet: planet::Planet::Earth,
+                function: "conjunct::conjunctions"
+            });
+        }
+    }
+
+    let step = if *body1 == Body::Moon || *body2 == Body::Moon { 0.25 } else { 1.0 };
+
+    let diff_in_asc = |JD: f64| {
+        let (p1, p2) = (eq_point(body1, JD), eq_point(body2, JD));
+        (p1.asc - p2.asc).sin()
+    };
+    let diff_in_long = |JD: f64| {
+        let (p1, p2) = (ecl_point(body1, JD), ecl_point(body2, JD));
+        (p1.long - p2.long).sin()
+    };
+    let sepr = |JD: f64| ecl_point(body1, JD).anglr_sepr(&ecl_point(body2, JD));
+
+    let n = ((JD_end - JD_start).max(0.0) / step).ceil() as usize;
+    let times: Vec<f64> = (0..(n + 1))
+        .map(|i| (JD_start + (i as f64)*step).min(JD_end))
+        .collect();
+
+    let mut asc_diffs = Vec::with_capacity(n + 1);
+    let mut long_diffs = Vec::with_capacity(n + 1);
+    let mut seprs = Vec::with_capacity(n + 1);
+    for &JD in &times {
+        let (e1, e2) = (ecl_point(body1, JD), ecl_point(body2, JD));
+        let (q1, q2) = (eq_point(body1, JD), eq_point(body2, JD));
+        asc_diffs.push((q1.asc - q2.asc).sin());
+        long_diffs.push((e1.long - e2.long).sin());
+        seprs.push(e1.anglr_sepr(&e2));
+    }
+
+    let mut conjunctions = Vec::new();
+    let mut push = |conj_type, JD: f64| conjunctions.push(Conjunction {
+        conj_type,
+        JD,
+        sepr: sepr(JD)
+    });
+
+    for i in 0..n {
+        if asc_diffs[i] * asc_diffs[i + 1] <= 0.0 && asc_diffs[i] != 0.0 {
+            if let Some(JD) = search::bisect(&diff_in_asc, times[i], times[i + 1]) {
+                let (p1, p2) = (eq_point(body1, JD), eq_point(body2, JD));
+                if (p1.asc - p2.asc).cos() > 0.0 {
+                    push(ConjunctType::InAsc, JD);
+                }
+            }
+        }
+        if long_diffs[i] * long_diffs[i + 1] <= 0.0 && long_diffs[i] != 0.0 {
+            if let Some(JD) = search::bisect(&diff_in_long, times[i], times[i + 1]) {
+                let (p1, p2) = (ecl_point(body1, JD), ecl_point(body2, JD));
+                if (p1.long - p2.long).cos() > 0.0 {
+                    push(ConjunctType::InLong, JD);
+                }
+            }
+        }
+        if i > 0 && seprs[i] < seprs[i - 1] && seprs[i] <= seprs[i + 1] {
+            let JD = search::golden_section_min(sepr, times[i - 1], times[i + 1]);
+            push(ConjunctType::ClosestApproach, JD);
+        }
+    }
+
+    conjunctions.sort_by(|a, b| a.JD.partial_cmp(&b.JD).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(conjunctions)
+
+}
+
+fn ecl_point(body: &Body, JD: f64) -> coords::EclPoint {
+
+    let p = eq_point(body, JD);
+    let oblq = true_oblq(JD);
+
+    coords::EclPoint {
+        long: angle::limit_to_two_PI(coords::ecl_long_frm_eq(Radians(p.asc), Radians(p.dec), Radians(oblq))),
+        lat: coords::ecl_lat_frm_eq(Radians(p.asc), Radians(p.dec), Radians(oblq))
+    }
+
+}
+
+#[inline]
+fn eq_point(body: &Body, JD: f64) -> coords::EqPoint {
+
+    transit::apprnt_eq_coords(body, JD)
+
+}
+
+fn true_oblq(JD: f64) -> f64 {
+
+    ecliptic::mn_oblq_laskar(JD) + nutation::nutation(JD).1
+
+}
@@ -21,8 +21,17 @@ THE SOFTWARE.
 */
 
 //! Transform between coordinate systems
+//!
+//! Angle arguments documented as *in radians* are taken as any
+//! [Angle](../angle/trait.Angle.html), such as
+//! [Radians](../angle/struct.Radians.html),
+//! [Degrees](../angle/struct.Degrees.html) or
+//! [HourAngle](../angle/struct.HourAngle.html). Plain `f64` values
+//! are taken to be in radians.
 
 use angle;
+use angle::Angle;
+use angle::Radians;
 
 /// Represents a point on the geographical surface of the Earth
 #[derive(Debug)]
@@ -34,10 +43,16 @@ pub struct GeographPoint {
 }
 
 impl GeographPoint {
+    /// Creates a point from its geographical longitude and latitude in any
+    /// [Angle](../angle/trait.Angle.html) unit
+    pub fn new<A: Angle, B: Angle>(long: A, lat: B) -> GeographPoint {
+        GeographPoint { long: long.rad(), lat: lat.rad() }
+    }
+
     pub fn anglr_sepr(&self, other_point: &GeographPoint) -> f64 {
         angle::anglr_sepr (
-            self.long, self.lat,
-            other_point.long, other_point.lat
+            Radians(self.long), Radians(self.lat),
+            Radians(other_point.long), Radians(other_point.lat)
         )
     }
 }
@@ -52,10 +67,16 @@ pub struct EqPoint {
 }
 
 impl EqPoint {
+    /// Creates a point from its right ascension and declination in any
+    /// [Angle](../angle/trait.Angle.html) unit
+    pub fn new<A: Angle, B: Angle>(asc: A, dec: B) -> EqPoint {
+        EqPoint { asc: asc.rad(), dec: dec.rad() }
+    }
+
     pub fn anglr_sepr(&self, other_point: &EqPoint) -> f64 {
         angle::anglr_sepr (
-            self.asc, self.dec,
-            other_point.asc, other_point.dec
+            Radians(self.asc), Radians(self.dec),
+            Radians(other_point.asc), Radians(other_point.dec)
         )
     }
 }
@@ -70,10 +91,16 @@ pub struct EclPoint {
 }
 
 impl EclPoint {
+    /// Creates a point from its ecliptic longitude and latitude in any
+    /// [Angle](../angle/trait.Angle.html) unit
+    pub fn new<A: Angle, B: Angle>(long: A, lat: B) -> EclPoint {
+        EclPoint { long: long.rad(), lat: lat.rad() }
+    }
+
     pub fn anglr_sepr(&self, other_point: &EclPoint) -> f64 {
         angle::anglr_sepr (
-            self.long, self.lat,
-            other_point.long, other_point.lat
+            Radians(self.long), Radians(self.lat),
+            Radians(other_point.long), Radians(other_point.lat)
         )
     }
 }
@@ -93,7 +120,12 @@ sidereal time
 * `asc`: Right ascension *| in radians*
 **/
 #[inline]
-pub fn hr_angl_frm_observer_long(green_sidreal: f64, observer_long: f64, asc: f64) -> f64 {
+pub fn hr_angl_frm_observer_long<A: Angle, B: Angle, C: Angle>(
+    green_sidreal: A, observer_long: B, asc: C
+) -> f64 {
+
+    let (green_sidreal, observer_long, asc) =
+        (green_sidreal.rad(), observer_long.rad(), asc.rad());
 
     green_sidreal - observer_long - asc
 
@@ -113,7 +145,9 @@ ascension
 * `asc`: Right ascension *| in radians*
 **/
 #[inline]
-pub fn hr_angl_frm_loc_sidr(local_sidreal: f64, asc: f64) -> f64 {
+pub fn hr_angl_frm_loc_sidr<A: Angle, B: Angle>(local_sidreal: A, asc: B) -> f64 {
+
+    let (local_sidreal, asc) = (local_sidreal.rad(), asc.rad());
 
     local_sidreal - asc
 
@@ -134,7 +168,9 @@ Computes the ecliptic longitude from equatorial coordinates
                     nutation, then *true* obliquity. If not, then
                     *mean* obliquity. *| in radians*
 **/
-pub fn ecl_long_frm_eq(asc: f64, dec: f64, oblq_eclip: f64,) -> f64 {
+pub fn ecl_long_frm_eq<A: Angle, B: Angle, C: Angle>(asc: A, dec: B, oblq_eclip: C) -> f64 {
+
+    let (asc, dec, oblq_eclip) = (asc.rad(), dec.rad(), oblq_eclip.rad());
 
     (
         asc.sin() * oblq_eclip.cos()
@@ -158,7 +194,9 @@ Computes the ecliptic latitude from equatorial coordinates
                     nutation, then *true* obliquity. If not, then
                     *mean* obliquity. *| in radians*
 **/
-pub fn ecl_lat_frm_eq(asc: f64, dec: f64, oblq_eclip: f64) -> f64 {
+pub fn ecl_lat_frm_eq<A: Angle, B: Angle, C: Angle>(asc: A, dec: B, oblq_eclip: C) -> f64 {
+
+    let (asc, dec, oblq_eclip) = (asc.rad(), dec.rad(), oblq_eclip.rad());
 
     (
         dec.sin() * oblq_eclip.cos()
@@ -208,7 +246,9 @@ Computes the right ascension from ecliptic coordinates
                     for nutation, then *true* obliquity. If not, then
                     *mean* obliquity. *| in radians*
 **/
-pub fn asc_frm_ecl(ecl_long: f64, ecl_lat: f64, oblq_eclip: f64) -> f64 {
+pub fn asc_frm_ecl<A: Angle, B: Angle, C: Angle>(ecl_long: A, ecl_lat: B, oblq_eclip: C) -> f64 {
+
+    let (ecl_long, ecl_lat, oblq_eclip) = (ecl_long.rad(), ecl_lat.rad(), oblq_eclip.rad());
 
     (
         ecl_long.sin() * oblq_eclip.cos()
@@ -232,7 +272,9 @@ Computes the declination from ecliptic coordinates
                     for nutation, then *true* obliquity. If not, then
                     *mean* obliquity. *| in radians*
 **/
-pub fn dec_frm_ecl(ecl_long: f64, ecl_lat: f64, oblq_eclip: f64) -> f64 {
+pub fn dec_frm_ecl<A: Angle, B: Angle, C: Angle>(ecl_long: A, ecl_lat: B, oblq_eclip: C) -> f64 {
+
+    let (ecl_long, ecl_lat, oblq_eclip) = (ecl_long.rad(), ecl_lat.rad(), oblq_eclip.rad());
 
     (
         ecl_lat.sin() * oblq_eclip.cos()
@@ -280,7 +322,9 @@ Computes the azimuth from equatorial coordinates
 * `dec`: Declination *| in radians*
 * `observer_lat`: Observer's geographical latitude *| in radians*
 **/
-pub fn az_frm_eq(hour_angle: f64, dec: f64, observer_lat: f64) -> f64 {
+pub fn az_frm_eq<A: Angle, B: Angle, C: Angle>(hour_angle: A, dec: B, observer_lat: C) -> f64 {
+
+    let (hour_angle, dec, observer_lat) = (hour_angle.rad(), dec.rad(), observer_lat.rad());
 
     hour_angle.sin().atan2 (
         hour_angle.cos()  * observer_lat.sin()
@@ -302,7 +346,9 @@ Computes the altitude from equatorial coordinates
 * `dec`: Declination *| in radians*
 * `observer_lat`: Observer's geographical latitude *| in radians*
 **/
-pub fn alt_frm_eq(hour_angle: f64, dec: f64, observer_lat: f64) -> f64 {
+pub fn alt_frm_eq<A: Angle, B: Angle, C: Angle>(hour_angle: A, dec: B, observer_lat: C) -> f64 {
+
+    let (hour_angle, dec, observer_lat) = (hour_angle.rad(), dec.rad(), observer_lat.rad());
 
     (
         observer_lat.sin() * dec.sin()
@@ -348,7 +394,9 @@ Computes the hour angle from local horizontal coordinates
 * `alt`: Altitude *| in radians*
 * `observer_lat`: Observer's geographical latitude *| in radians*
 **/
-pub fn hr_angl_frm_hz(az: f64, alt: f64, observer_lat: f64) -> f64 {
+pub fn hr_angl_frm_hz<A: Angle, B: Angle, C: Angle>(az: A, alt: B, observer_lat: C) -> f64 {
+
+    let (az, alt, observer_lat) = (az.rad(), alt.rad(), observer_lat.rad());
 
     az.sin().atan2 (
         az.cos() * observer_lat.sin()
@@ -370,7 +418,9 @@ Computes the declination from local horizontal coordinates
 * `alt`: Altitude *| in radians*
 * `observer_lat`: Observer's geographical latitude *| in radians*
 **/
-pub fn dec_frm_hz(az: f64, alt: f64, observer_lat: f64) -> f64 {
+pub fn dec_frm_hz<A: Angle, B: Angle, C: Angle>(az: A, alt: B, observer_lat: C) -> f64 {
+
+    let (az, alt, observer_lat) = (az.rad(), alt.rad(), observer_lat.rad());
 
     (
         observer_lat.sin() * alt.sin()
@@ -394,7 +444,9 @@ Computes the galactic longitude from equatorial coordinates
 The equatorial coordinates passed are assumed to be referred to the
 standard equinox of B1950.0.
 **/
-pub fn gal_long_frm_eq(asc: f64, dec: f64) -> f64 {
+pub fn gal_long_frm_eq<A: Angle, B: Angle>(asc: A, dec: B) -> f64 {
+
+    let (asc, dec) = (asc.rad(), dec.rad());
 
     303_f64.to_radians()
   - (192.25_f64.to_radians() - asc).sin().atan2 (
@@ -419,7 +471,9 @@ Computes the galactic latitude from equatorial coordinates
 The equatorial coordinates passed are assumed to be referred to the
 standard equinox of B1950.0.
 **/
-pub fn gal_lat_frm_eq(asc: f64, dec: f64) -> f64 {
+pub fn gal_lat_frm_eq<A: Angle, B: Angle>(asc: A, dec: B) -> f64 {
+
+    let (asc, dec) = (asc.rad(), dec.rad());
 
     (
         dec.sin() * 27.4_f64.to_radians().sin()
@@ -469,7 +523,9 @@ of  B1950.0.
 * `gal_long`: Galactic longitude *| in radians*
 * `gal_lat`: Galactic latitude *| in radians*
 **/
-pub fn asc_frm_gal(gal_long: f64, gal_lat: f64) -> f64 {
+pub fn asc_frm_gal<A: Angle, B: Angle>(gal_long: A, gal_lat: B) -> f64 {
+
+    let (gal_long, gal_lat) = (gal_long.rad(), gal_lat.rad());
 
       12.25_f64.to_radians()
     + (gal_long - 123_f64.to_radians()).sin().atan2 (
@@ -494,7 +550,9 @@ of  B1950.0.
 * `gal_long`: Galactic longitude *| in radians*
 * `gal_lat`: Galactic latitude *| in radians*
 **/
-pub fn dec_frm_gal(gal_long: f64, gal_lat: f64) -> f64 {
+pub fn dec_frm_gal<A: Angle, B: Angle>(gal_long: A, gal_lat: B) -> f64 {
+
+    let (gal_long, gal_lat) = (gal_long.rad(), gal_lat.rad());
 
     (
         gal_lat.sin() * 27.4_f64.to_radians().sin()
@@ -0,0 +1,280 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Lunar eclipses
+
+use lunar;
+use planet;
+use std;
+use sun;
+use time;
+
+use search::{bisect, golden_section_min};
+use super::{AU, moon_apprnt_eq_pos, sun_apprnt_eq_pos, table_value, TABLE_LEN, TABLE_STEP};
+
+/// Represents a type of lunar eclipse
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EclipseType {
+    /// No eclipse
+    None,
+    /// Penumbral eclipse
+    Penumbral,
+    /// Partial (umbral) eclipse
+    Partial,
+    /// Total eclipse
+    Total,
+}
+
+/// Represents a rule for enlarging the Earth's shadow to account for
+/// the Earth's atmosphere
+#[derive(Copy, Clone, Debug)]
+pub enum ShadowRule {
+    /// A. Danjon's rule, which increases the Earth's radius by 1/85
+    Danjon,
+    /// W. Chauvenet's rule, which enlarges both shadows by 1/50
+    Chauvenet,
+}
+
+/// Holds the circumstances of a lunar eclipse
+///
+/// *All times are Julian Ephemeris days*
+#[derive(Debug)]
+pub struct Eclipse {
+    /// Type of the eclipse
+    pub eclipse_type: EclipseType,
+    /// Greatest eclipse, when the Moon's center passes closest to the
+    /// axis of the Earth's shadow
+    pub JD: f64,
+    /// Least distance of the Moon's center from the shadow axis
+    /// *| in equatorial Earth radii*, negative when the Moon passes
+    /// south of the axis. `None` when there's no eclipse.
+    pub gamma: Option<f64>,
+    /// Penumbral magnitude at greatest eclipse, as a fraction of the
+    /// Moon's diameter
+    pub pen_mag: f64,
+    /// Umbral magnitude at greatest eclipse, as a fraction of the
+    /// Moon's diameter. *Negative for a penumbral eclipse.*
+    pub umbral_mag: f64,
+    /// First contact with the penumbra
+    pub P1: Option<f64>,
+    /// First contact with the umbra, when the partial phase begins
+    pub U1: Option<f64>,
+    /// Second contact with the umbra, when the total phase begins
+    pub U2: Option<f64>,
+    /// Third contact with the umbra, when the total phase ends
+    pub U3: Option<f64>,
+    /// Last contact with the umbra, when the partial phase ends
+    pub U4: Option<f64>,
+    /// Last contact with the penumbra
+    pub P4: Option<f64>,
+}
+
+/**
+Computes the circumstances of the lunar eclipse, if any, at the Full
+Moon closest to a given date
+
+# Returns
+
+* `eclipse`: The [Eclipse](./struct.Eclipse.html)
+
+The Moon's position relative to the Earth's shadow is computed from the
+apparent geocentric positions of the Sun (VSOP87) and of the Moon
+(ELP-2000/82). The times of contacts are in error by no more than a
+few seconds relative to those theories, although the enlargement of
+the shadow by the atmosphere is itself uncertain by about a minute.
+
+# Arguments
+
+* `date`       : Date of interest, close to the Full Moon
+* `shadow_rule`: The [ShadowRule](./enum.ShadowRule.html) used to enlarge
+                 the Earth's shadow
+**/
+pub fn eclipse(date: &time::Date, shadow_rule: &ShadowRule) -> Eclipse {
+
+    let JD_full_moon = lunar::time_of_phase(date, &lunar::Phase::Full);
+
+    let (moon_ecl_point, _) = lunar::geocent_ecl_pos(JD_full_moon);
+    let arg_of_lat = moon_ecl_point.long
+                   - lunar::true_ascend_node(time::julian_cent(JD_full_moon));
+    if arg_of_lat.sin().abs() > 0.36 {
+        return no_eclipse(JD_full_moon);
+    }
+
+    let table = ShadowTable::new(JD_full_moon, shadow_rule);
+
+    let JD = golden_section_min(
+        |JD: f64| table.at(JD).dist, JD_full_moon - 0.15, JD_full_moon + 0.15
+    );
+    let s = table.at(JD);
+
+    let pen_mag = (s.pen_rad + s.moon_rad - s.dist) / (2.0 * s.moon_rad);
+    let umbral_mag = (s.umbral_rad + s.moon_rad - s.dist) / (2.0 * s.moon_rad);
+
+    let eclipse_type =
+             if umbral_mag >= 1.0 { EclipseType::Total }
+        else if umbral_mag > 0.0  { EclipseType::Partial }
+        else if pen_mag > 0.0     { EclipseType::Penumbral }
+        else                      { return no_eclipse(JD); };
+
+    let contacts = |rad: &dyn Fn(&Shadow) -> f64| {
+        let f = |JD: f64| { let s = table.at(JD); s.dist - rad(&s) };
+        (bisect(&f, JD - 0.2, JD), bisect(&f, JD, JD + 0.2))
+    };
+
+    let (P1, P4) = contacts(&|s: &Shadow| s.pen_rad + s.moon_rad);
+    let (U1, U4) = contacts(&|s: &Shadow| s.umbral_rad + s.moon_rad);
+    let (U2, U3) = contacts(&|s: &Shadow| s.umbral_rad - s.moon_rad);
+
+    Eclipse {
+        eclipse_type,
+        JD,
+        gamma: Some(s.dist / s.moon_parllx * s.y.signum()),
+        pen_mag,
+        umbral_mag,
+        P1,
+        U1,
+        U2,
+        U3,
+        U4,
+        P4,
+    }
+
+}
+
+fn no_eclipse(JD: f64) -> Eclipse {
+
+    Eclipse {
+        eclipse_type: EclipseType::None,
+        JD,
+        gamma: None,
+        pen_mag: 0.0,
+        umbral_mag: 0.0,
+        P1: None, U1: None, U2: None, U3: None, U4: None, P4: None,
+    }
+
+}
+
+/// The Moon's position relative to the Earth's shadow, and the
+/// angular radii of the shadows and the Moon, *| in radians*
+struct Shadow {
+    x: f64,
+    y: f64,
+    dist: f64,
+    moon_parllx: f64,
+    moon_rad: f64,
+    pen_rad: f64,
+    umbral_rad: f64,
+}
+
+fn shadow(JD: f64, shadow_rule: &ShadowRule) -> Shadow {
+
+    let (sun_eq_point, sun_dist) = sun_apprnt_eq_pos(JD);
+    let (moon_eq_point, moon_dist) = moon_apprnt_eq_pos(JD);
+
+    let asc0 = sun_eq_point.asc + std::f64::consts::PI;
+    let dec0 = -sun_eq_point.dec;
+    let H = moon_eq_point.asc - asc0;
+
+    let x = moon_eq_point.dec.cos() * H.sin();
+    let y = moon_eq_point.dec.sin()*dec0.cos() - moon_eq_point.dec.cos()*dec0.sin()*H.cos();
+
+    let moon_parllx = (1.0 / moon_dist).asin();
+    let sun_parllx = (1.0 / sun_dist).asin();
+    let sun_rad = sun::semidiameter(sun_dist * planet::earth::eq_rad() / AU).to_radians();
+
+    let (pen_rad, umbral_rad) = match *shadow_rule {
+        ShadowRule::Danjon => (
+            1.01*moon_parllx + sun_parllx + sun_rad,
+            1.01*moon_parllx + sun_parllx - sun_rad
+        ),
+        ShadowRule::Chauvenet => (
+            1.02 * (moon_parllx + sun_parllx + sun_rad),
+            1.02 * (moon_parllx + sun_parllx - sun_rad)
+        ),
+    };
+
+    Shadow {
+        x,
+        y,
+        dist: (x*x + y*y).sqrt(),
+        moon_parllx,
+        moon_rad: 0.272481 * moon_parllx.sin(),
+        pen_rad,
+        umbral_rad,
+    }
+
+}
+
+/// The Moon's position relative to the Earth's shadow, sampled at
+/// `TABLE_LEN` instants two hours apart, for interpolation
+struct ShadowTable {
+    JD: f64,
+    x: [f64; TABLE_LEN],
+    y: [f64; TABLE_LEN],
+    moon_parllx: [f64; TABLE_LEN],
+    pen_rad: [f64; TABLE_LEN],
+    umbral_rad: [f64; TABLE_LEN],
+}
+
+impl ShadowTable {
+
+    fn new(JD: f64, shadow_rule: &ShadowRule) -> ShadowTable {
+
+        let mut table = ShadowTable {
+            JD,
+            x: [0.0; TABLE_LEN], y: [0.0; TABLE_LEN], moon_parllx: [0.0; TABLE_LEN],
+            pen_rad: [0.0; TABLE_LEN], umbral_rad: [0.0; TABLE_LEN],
+        };
+
+        for i in 0..TABLE_LEN {
+            let s = shadow(JD + ((i as f64) - (TABLE_LEN / 2) as f64)*TABLE_STEP, shadow_rule);
+            table.x[i] = s.x;
+            table.y[i] = s.y;
+            table.moon_parllx[i] = s.moon_parllx;
+            table.pen_rad[i] = s.pen_rad;
+            table.umbral_rad[i] = s.umbral_rad;
+        }
+
+        table
+
+    }
+
+    fn at(&self, JD: f64) -> Shadow {
+
+        let n = (JD - self.JD) / TABLE_STEP;
+        let x = table_value(&self.x, n);
+        let y = table_value(&self.y, n);
+        let moon_parllx = table_value(&self.moon_parllx, n);
+
+        Shadow {
+            x,
+            y,
+            dist: (x*x + y*y).sqrt(),
+            moon_parllx,
+            moon_rad: 0.272481 * moon_parllx.sin(),
+            pen_rad: table_value(&self.pen_rad, n),
+            umbral_rad: table_value(&self.umbral_rad, n),
+        }
+
+    }
+
+}
@@ -0,0 +1,121 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Solar and lunar eclipses
+
+pub mod lunar;
+pub mod solar;
+
+use coords;
+use interpol;
+use planet;
+use sun;
+
+/// Astronomical unit *| in kilometers*
+const AU: f64 = 149597870.7;
+
+/**
+Computes the Sun's apparent geocentric equatorial position
+
+# Returns
+
+`(sun_eq_point, sun_earth_dist)`
+
+* `sun_eq_point`  : Equatorial point of the Sun *| in radians*, referred
+                    to the true equator and equinox of the date
+* `sun_earth_dist`: Sun-Earth distance *| in equatorial Earth radii*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+fn sun_apprnt_eq_pos(JD: f64) -> (coords::EqPoint, f64) {
+
+    let (eq_point, rad_vec) = sun::apprnt_eq_pos(JD);
+
+    (eq_point, rad_vec * AU / planet::earth::eq_rad())
+
+}
+
+/**
+Computes the Moon's apparent geocentric equatorial position
+
+# Returns
+
+`(moon_eq_point, moon_earth_dist)`
+
+* `moon_eq_point`  : Equatorial point of the Moon *| in radians*, referred
+                     to the true equator and equinox of the date
+* `moon_earth_dist`: Moon-Earth distance *| in equatorial Earth radii*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+fn moon_apprnt_eq_pos(JD: f64) -> (coords::EqPoint, f64) {
+
+    let (eq_point, rad_vec) = ::lunar::apprnt_eq_pos(JD);
+
+    (eq_point, rad_vec / planet::earth::eq_rad())
+
+}
+
+/**
+Computes the geocentric equatorial rectangular coordinates of a point
+
+# Arguments
+
+* `eq_point`: Equatorial point *| in radians*
+* `dist`    : Distance from the Earth's center
+**/
+#[inline]
+fn eq_rect_coords(eq_point: &coords::EqPoint, dist: f64) -> (f64, f64, f64) {
+
+    (
+        dist * eq_point.dec.cos() * eq_point.asc.cos(),
+        dist * eq_point.dec.cos() * eq_point.asc.sin(),
+        dist * eq_point.dec.sin()
+    )
+
+}
+
+/// Number of samples in a table of the circumstances of an eclipse.
+/// They span ten hours on either side of the middle one, which covers
+/// the greatest eclipse, searched for within 0.15 day of the middle
+/// sample, and the contacts, searched for within 0.2 day of that.
+const TABLE_LEN: usize = 11;
+
+/// Interval between two samples of a table *| in days*
+const TABLE_STEP: f64 = 2.0 / 24.0;
+
+/// Interpolates an intermediate value from values tabulated at
+/// `TABLE_STEP` intervals, with `n` measured from the middle value,
+/// using the five values nearest to it
+fn table_value(v: &[f64; TABLE_LEN], n: f64) -> f64 {
+
+    let mid = (TABLE_LEN / 2) as f64;
+    let i = (n + mid).round().max(2.0).min((TABLE_LEN - 3) as f64);
+    let j = i as usize;
+
+    interpol::five_values(v[j - 2], v[j - 1], v[j], v[j + 1], v[j + 2], n + mid - i)
+
+}
@@ -0,0 +1,498 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Solar eclipses
+
+use angle;
+use angle::Radians;
+use coords;
+use ecliptic;
+use lunar;
+use nutation;
+use planet;
+use time;
+use std;
+
+use search::{bisect, golden_section_min};
+use super::{eq_rect_coords, moon_apprnt_eq_pos, sun_apprnt_eq_pos,
+            table_value, TABLE_LEN, TABLE_STEP};
+
+/// Represents a type of solar eclipse
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EclipseType {
+    /// No eclipse
+    None,
+    /// Partial eclipse
+    Partial,
+    /// Annular eclipse
+    Annular,
+    /// Total eclipse
+    Total,
+    /// Hybrid (annular-total) eclipse
+    Hybrid,
+}
+
+/// Holds the Besselian elements of a solar eclipse at an instant
+///
+/// *All lengths are in equatorial Earth radii, and all angles are in
+/// radians*
+#[derive(Debug)]
+pub struct BesselianElements {
+    /// X coordinate of the shadow axis in the fundamental plane
+    pub x: f64,
+    /// Y coordinate of the shadow axis in the fundamental plane
+    pub y: f64,
+    /// Declination of the shadow axis
+    pub d: f64,
+    /// Greenwich hour angle of the shadow axis
+    pub mu: f64,
+    /// Radius of the penumbral cone in the fundamental plane
+    pub l1: f64,
+    /// Radius of the umbral cone in the fundamental plane,
+    /// negative for a total eclipse
+    pub l2: f64,
+    /// Tangent of the semi-vertex angle of the penumbral cone
+    pub tan_f1: f64,
+    /// Tangent of the semi-vertex angle of the umbral cone
+    pub tan_f2: f64,
+}
+
+/// Holds the global circumstances of a solar eclipse
+#[derive(Debug)]
+pub struct Eclipse {
+    /// Type of the eclipse
+    pub eclipse_type: EclipseType,
+    /// Julian Ephemeris day of greatest eclipse, when the shadow
+    /// axis passes closest to the Earth's center
+    pub JD: f64,
+    /// Least distance of the shadow axis from the Earth's center
+    /// *| in equatorial Earth radii*, negative when the axis passes
+    /// south of the center
+    pub gamma: f64,
+    /// Magnitude at greatest eclipse, as a fraction of the Sun's
+    /// diameter. *Zero when there is no eclipse.*
+    pub mag: f64,
+}
+
+/// Holds the local circumstances of a solar eclipse for an observer
+///
+/// *All times are Julian Ephemeris days*
+#[derive(Debug)]
+pub struct LocalCircumstances {
+    /// Type of the eclipse as seen by the observer. Never
+    /// `EclipseType::Hybrid`.
+    pub eclipse_type: EclipseType,
+    /// First contact, when the partial phase begins
+    pub C1: Option<f64>,
+    /// Second contact, when the total or annular phase begins
+    pub C2: Option<f64>,
+    /// Third contact, when the total or annular phase ends
+    pub C3: Option<f64>,
+    /// Fourth contact, when the partial phase ends
+    pub C4: Option<f64>,
+    /// Maximum eclipse
+    pub max: f64,
+    /// Magnitude at maximum eclipse, as a fraction of the Sun's
+    /// diameter
+    pub mag: f64,
+    /// Fraction of the area of the Sun's disk obscured by the
+    /// Moon at maximum eclipse
+    pub obscuration: f64,
+    /// Geometric altitude of the Sun at maximum eclipse *| in radians*.
+    /// The eclipse is not visible if it is negative.
+    pub sun_alt: f64,
+}
+
+/// Ratio of the Moon's radius to the Earth's equatorial radius, for
+/// the penumbral cone
+const K1: f64 = 0.272488;
+
+/// Ratio of the Moon's radius to the Earth's equatorial radius, for
+/// the umbral cone
+const K2: f64 = 0.272281;
+
+/// Ratio of the Sun's radius to the Earth's equatorial radius
+const SUN_RAD: f64 = 109.1222;
+
+/**
+Computes the Besselian elements of a solar eclipse
+
+# Returns
+
+* `bessel_elements`: The [BesselianElements](./struct.BesselianElements.html)
+
+The elements are built from the apparent geocentric positions of
+the Sun (VSOP87) and of the Moon (ELP-2000/82).
+
+# Arguments
+
+* `JD`     : Julian (Ephemeris) day
+* `delta_t`: ΔT for `JD`, needed only for the hour angle `mu`
+**/
+pub fn besselian_elements(JD: f64, delta_t: f64) -> BesselianElements {
+
+    let (sun_eq_point, sun_dist) = sun_apprnt_eq_pos(JD);
+    let (moon_eq_point, moon_dist) = moon_apprnt_eq_pos(JD);
+
+    let (xs, ys, zs) = eq_rect_coords(&sun_eq_point, sun_dist);
+    let (xm, ym, zm) = eq_rect_coords(&moon_eq_point, moon_dist);
+
+    let (gx, gy, gz) = (xs - xm, ys - ym, zs - zm);
+    let g = (gx*gx + gy*gy + gz*gz).sqrt();
+    let a = gy.atan2(gx);
+    let d = (gz / g).asin();
+
+    let H = moon_eq_point.asc - a;
+    let x = moon_dist * moon_eq_point.dec.cos() * H.sin();
+    let y = moon_dist * (
+        moon_eq_point.dec.sin() * d.cos()
+      - moon_eq_point.dec.cos() * d.sin() * H.cos()
+    );
+    let z = moon_dist * (
+        moon_eq_point.dec.sin() * d.sin()
+      + moon_eq_point.dec.cos() * d.cos() * H.cos()
+    );
+
+    let f1 = ((SUN_RAD + K1) / g).asin();
+    let f2 = ((SUN_RAD - K2) / g).asin();
+
+    BesselianElements {
+        x,
+        y,
+        d,
+        mu: angle::limit_to_two_PI(apprnt_greenwhich_sidr(JD, delta_t) - a),
+        l1: z*f1.tan() + K1/f1.cos(),
+        l2: z*f2.tan() - K2/f2.cos(),
+        tan_f1: f1.tan(),
+        tan_f2: f2.tan(),
+    }
+
+}
+
+/**
+Computes the global circumstances of the solar eclipse, if any, at
+the New Moon closest to a given date
+
+# Returns
+
+* `eclipse`: The [Eclipse](./struct.Eclipse.html)
+
+# Arguments
+
+* `date`: Date of interest, close to the New Moon
+**/
+pub fn eclipse(date: &time::Date) -> Eclipse {
+
+    let JD_new_moon = lunar::time_of_phase(date, &lunar::Phase::New);
+    let delta_t = time::delta_t(date.year as i32, date.month as u8);
+    let table = ElementsTable::new(JD_new_moon, delta_t);
+
+    let mut JD = JD_new_moon;
+    for _ in 0..10 {
+        let h = 1.0 / 1440.0;
+        let e = table.at(JD);
+        let e1 = table.at(JD + h);
+        let dx = (e1.x - e.x) / h;
+        let dy = (e1.y - e.y) / h;
+        let correction = (e.x*dx + e.y*dy) / (dx*dx + dy*dy);
+        JD -= correction;
+
+        if correction.abs() < 0.0000001 {
+            break;
+        }
+    }
+
+    let e = table.at(JD);
+    let gamma = (e.x*e.x + e.y*e.y).sqrt() * e.y.signum();
+
+    let ecc_sqr = planet::earth::flat_fac() * (2.0 - planet::earth::flat_fac());
+    let rho1 = (1.0 - ecc_sqr*e.d.cos()*e.d.cos()).sqrt();
+    let y1 = e.y / rho1;
+    let m = (e.x*e.x + y1*y1).sqrt();
+
+    let (eclipse_type, mag) =
+        if m < 1.0 {
+            let zeta = (1.0 - e.x*e.x - y1*y1).sqrt();
+            let L1 = e.l1 - zeta*e.tan_f1;
+            let L2 = e.l2 - zeta*e.tan_f2;
+            let eclipse_type =
+                     if e.l2 < 0.0 { EclipseType::Total }
+                else if L2 < 0.0   { EclipseType::Hybrid }
+                else               { EclipseType::Annular };
+
+            (eclipse_type, (L1 - L2) / (L1 + L2))
+        }
+        else if m < 1.0 + e.l1 {
+            let eclipse_type =
+                     if m >= 1.0 + e.l2.abs() { EclipseType::Partial }
+                else if e.l2 < 0.0            { EclipseType::Total }
+                else                          { EclipseType::Annular };
+
+            (eclipse_type, (e.l1 - (m - 1.0)) / (e.l1 + e.l2))
+        }
+        else {
+            (EclipseType::None, 0.0)
+        };
+
+    Eclipse {
+        eclipse_type,
+        JD,
+        gamma,
+        mag
+    }
+
+}
+
+/**
+Computes the local circumstances of a solar eclipse
+
+# Returns
+
+* `loc_circumstances`: The [LocalCircumstances](./struct.LocalCircumstances.html)
+
+The eclipse is considered without regard to the horizon, so
+contacts are returned even if the Sun is below it; check `sun_alt`
+to see if the eclipse is visible.
+
+# Arguments
+
+* `eclipse`       : The [Eclipse](./struct.Eclipse.html) returned by
+                    [eclipse()](./fn.eclipse.html)
+* `geograph_point`: Geographic point of the observer *| in radians*,
+                    with longitude measured positively westwards
+* `height`        : Observer's height above sea level *| in meters*
+* `delta_t`       : ΔT for the time of the eclipse
+**/
+pub fn local_circumstances (
+
+    eclipse        : &Eclipse,
+    geograph_point : &coords::GeographPoint,
+    height         : f64,
+    delta_t        : f64
+
+) -> LocalCircumstances {
+
+    let table = ElementsTable::new(eclipse.JD, delta_t);
+    let (rho_sin_phi, rho_cos_phi) = planet::earth::rho_sin_cos_phi(
+        geograph_point.lat, height
+    );
+    let observe = |JD: f64| {
+        observer_shadow(&table.at(JD), rho_sin_phi, rho_cos_phi, geograph_point.long)
+    };
+
+    let max = golden_section_min(
+        |JD: f64| observe(JD).0, eclipse.JD - 0.15, eclipse.JD + 0.15
+    );
+    let (delta, L1, L2) = observe(max);
+
+    let (sun_eq_point, _) = sun_apprnt_eq_pos(max);
+    let hour_angle = coords::hr_angl_frm_observer_long(
+        Radians(apprnt_greenwhich_sidr(max, delta_t)),
+        Radians(geograph_point.long),
+        Radians(sun_eq_point.asc)
+    );
+    let sun_alt = coords::alt_frm_eq(
+        Radians(hour_angle), Radians(sun_eq_point.dec), Radians(geograph_point.lat)
+    );
+
+    if delta >= L1 {
+        return LocalCircumstances {
+            eclipse_type: EclipseType::None,
+            C1: None, C2: None, C3: None, C4: None,
+            max,
+            mag: 0.0,
+            obscuration: 0.0,
+            sun_alt
+        };
+    }
+
+    let outer = |JD: f64| { let (d, l1, _) = observe(JD); d - l1 };
+    let C1 = bisect(&outer, max - 0.2, max);
+    let C4 = bisect(&outer, max, max + 0.2);
+
+    let (eclipse_type, C2, C3) =
+        if delta < L2.abs() {
+            let inner = |JD: f64| { let (d, _, l2) = observe(JD); d - l2.abs() };
+            (
+                if L2 < 0.0 { EclipseType::Total } else { EclipseType::Annular },
+                bisect(&inner, max - 0.01, max),
+                bisect(&inner, max, max + 0.01)
+            )
+        } else {
+            (EclipseType::Partial, None, None)
+        };
+
+    let sun_rad = (L1 + L2) / 2.0;
+    let moon_rad = (L1 - L2) / 2.0;
+
+    LocalCircumstances {
+        eclipse_type,
+        C1,
+        C2,
+        C3,
+        C4,
+        max,
+        mag: (L1 - delta) / (L1 + L2),
+        obscuration: obscuration(moon_rad / sun_rad, delta / sun_rad),
+        sun_alt
+    }
+
+}
+
+/// Besselian elements sampled at `TABLE_LEN` instants two hours apart,
+/// for interpolation
+struct ElementsTable {
+    JD: f64,
+    x: [f64; TABLE_LEN],
+    y: [f64; TABLE_LEN],
+    d: [f64; TABLE_LEN],
+    mu: [f64; TABLE_LEN],
+    l1: [f64; TABLE_LEN],
+    l2: [f64; TABLE_LEN],
+    tan_f1: [f64; TABLE_LEN],
+    tan_f2: [f64; TABLE_LEN],
+}
+
+impl ElementsTable {
+
+    fn new(JD: f64, delta_t: f64) -> ElementsTable {
+
+        let mut table = ElementsTable {
+            JD,
+            x: [0.0; TABLE_LEN], y: [0.0; TABLE_LEN], d: [0.0; TABLE_LEN], mu: [0.0; TABLE_LEN],
+            l1: [0.0; TABLE_LEN], l2: [0.0; TABLE_LEN], tan_f1: [0.0; TABLE_LEN], tan_f2: [0.0; TABLE_LEN],
+        };
+
+        for i in 0..TABLE_LEN {
+            let e = besselian_elements(JD + ((i as f64) - (TABLE_LEN / 2) as f64)*TABLE_STEP, delta_t);
+            table.x[i] = e.x;
+            table.y[i] = e.y;
+            table.d[i] = e.d;
+            table.mu[i] = e.mu;
+            table.l1[i] = e.l1;
+            table.l2[i] = e.l2;
+            table.tan_f1[i] = e.tan_f1;
+            table.tan_f2[i] = e.tan_f2;
+        }
+
+        for i in 1..TABLE_LEN {
+            while table.mu[i] < table.mu[i - 1] {
+                table.mu[i] += angle::TWO_PI;
+            }
+        }
+
+        table
+
+    }
+
+    fn at(&self, JD: f64) -> BesselianElements {
+
+        let n = (JD - self.JD) / TABLE_STEP;
+        let interpol = |v: &[f64; TABLE_LEN]| table_value(v, n);
+
+        BesselianElements {
+            x: interpol(&self.x),
+            y: interpol(&self.y),
+            d: interpol(&self.d),
+            mu: interpol(&self.mu),
+            l1: interpol(&self.l1),
+            l2: interpol(&self.l2),
+            tan_f1: interpol(&self.tan_f1),
+            tan_f2: interpol(&self.tan_f2),
+        }
+
+    }
+
+}
+
+/**
+Computes the observer's distance from the shadow axis and the radii
+of the penumbra and umbra at the observer
+
+# Returns
+
+`(delta, L1, L2)`, all in equatorial Earth radii
+**/
+fn observer_shadow (
+
+    e           : &BesselianElements,
+    rho_sin_phi : f64,
+    rho_cos_phi : f64,
+    long        : f64
+
+) -> (f64, f64, f64) {
+
+    let H = e.mu - long;
+
+    let xi = rho_cos_phi * H.sin();
+    let eta = rho_sin_phi*e.d.cos() - rho_cos_phi*H.cos()*e.d.sin();
+    let zeta = rho_sin_phi*e.d.sin() + rho_cos_phi*H.cos()*e.d.cos();
+
+    let u = e.x - xi;
+    let v = e.y - eta;
+
+    (
+        (u*u + v*v).sqrt(),
+        e.l1 - zeta*e.tan_f1,
+        e.l2 - zeta*e.tan_f2
+    )
+
+}
+
+/**
+Computes the fraction of the area of a disk of unit radius covered by
+another disk
+
+# Arguments
+
+* `r`: Radius of the covering disk
+* `c`: Distance between the centers of the two disks
+**/
+fn obscuration(r: f64, c: f64) -> f64 {
+
+    if c >= 1.0 + r {
+        0.0
+    }
+    else if c <= (1.0 - r).abs() {
+        if r < 1.0 { r * r } else { 1.0 }
+    }
+    else {
+        let area =
+            r * r * ((c*c + r*r - 1.0) / (2.0 * c * r)).acos()
+          + ((c*c + 1.0 - r*r) / (2.0 * c)).acos()
+          - 0.5 * (
+                (-c + r + 1.0) * (c + r - 1.0) * (c - r + 1.0) * (c + r + 1.0)
+            ).sqrt();
+
+        area / std::f64::consts::PI
+    }
+
+}
+
+fn apprnt_greenwhich_sidr(JD: f64, delta_t: f64) -> f64 {
+
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    time::apprnt_sidr(time::mn_sidr(JD - delta_t/86400.0), nut_in_long, true_oblq)
+
+}
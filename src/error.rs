@@ -0,0 +1,87 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Errors
+
+use planet;
+use std;
+
+/// Represents the causes of failure of the functions in this crate
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A planet that the function doesn't handle was passed to it
+    InvalidPlanet {
+        /// The planet that was passed
+        planet: planet::Planet,
+        /// Path of the function
+        function: &'static str,
+    },
+    /// An iterative method didn't converge
+    NoConvergence {
+        /// Path of the function
+        function: &'static str,
+        /// Number of iterations made before giving up
+        iterations: u32,
+    },
+    /// A Julian day outside the range handled by the function was passed
+    JulianDayOutOfRange(f64),
+    /// A date couldn't be computed or isn't valid
+    InvalidDate,
+    /// A string couldn't be parsed as a sexagesimal angle
+    InvalidAngle,
+    /// A line of a data table couldn't be parsed
+    InvalidTable {
+        /// Line number, starting from 1
+        line: usize,
+    },
+    /// A file couldn't be read
+    Io(std::io::ErrorKind),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Error::InvalidPlanet { planet, function } =>
+                write!(f, "Planet::{:?} was passed to the function {}()", planet, function),
+            Error::NoConvergence { function, iterations } =>
+                write!(f, "No convergence at {}() after {} iterations", function, iterations),
+            Error::JulianDayOutOfRange(JD) =>
+                write!(f, "The Julian day {} is out of range", JD),
+            Error::InvalidDate =>
+                write!(f, "Invalid date"),
+            Error::InvalidAngle =>
+                write!(f, "Invalid sexagesimal angle"),
+            Error::InvalidTable { line } =>
+                write!(f, "Invalid data at line {} of the table", line),
+            Error::Io(kind) =>
+                write!(f, "I/O error: {:?}", kind),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err.kind())
+    }
+}
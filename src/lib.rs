@@ -33,8 +33,11 @@ pub mod angle;
 pub mod asteroid;
 pub mod atmos;
 pub mod binary_star;
+pub mod conjunct;
 pub mod consts;
+pub mod eclipse;
 pub mod ecliptic;
+pub mod error;
 pub mod interpol;
 pub mod lunar;
 pub mod misc;
@@ -48,3 +51,7 @@ pub mod star;
 pub mod sun;
 pub mod time;
 pub mod transit;
+
+mod search;
+
+pub use error::Error;
@@ -23,7 +23,11 @@ THE SOFTWARE.
 //! The Earth's moon
 
 use angle;
+use angle::Radians;
 use coords;
+use ecliptic;
+use nutation;
+use search;
 use time;
 
 /**
@@ -715,6 +719,39 @@ pub fn geocent_ecl_pos(JD: f64) -> (coords::EclPoint, f64) {
 
 }
 
+/**
+Computes the apparent geocentric equatorial position of the Moon,
+referred to the true equator and equinox of the date
+
+# Returns
+
+`(moon_eq_point, rad_vec)`
+
+* `moon_eq_point`: Equatorial point of the Moon *| in radians*,
+                   corrected for nutation
+* `rad_vec`: Moon-Earth distance *| in kilometers*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apprnt_eq_pos(JD: f64) -> (coords::EqPoint, f64) {
+
+    let (moon_ecl_point, rad_vec) = geocent_ecl_pos(JD);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let long = moon_ecl_point.long + nut_in_long;
+
+    let eq_point = coords::EqPoint {
+        asc: coords::asc_frm_ecl(Radians(long), Radians(moon_ecl_point.lat), Radians(true_oblq)),
+        dec: coords::dec_frm_ecl(Radians(long), Radians(moon_ecl_point.lat), Radians(true_oblq))
+    };
+
+    (eq_point, rad_vec)
+
+}
+
 /**
 Computes the longitude of the mean ascending node of the Moon
 
@@ -1274,3 +1311,116 @@ pub fn time_of_phase(date: &time::Date, phase: &Phase) -> f64 {
     JD
 
 }
+
+/// Represents an apsis of the Moon's orbit
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Apsis {
+    /// Least distance from the Earth
+    Perigee,
+    /// Greatest distance from the Earth
+    Apogee
+}
+
+/**
+Computes the time and distance of the Moon's passage through perigee or
+apogee
+
+# Returns
+
+`(JD, earth_moon_dist)`
+
+* `JD`             : Julian (Ephemeris) day of the passage that is
+                     closest to `date`
+* `earth_moon_dist`: Earth-Moon distance at the time *| in kilometers*
+
+The time of the mean apsis (*Meeus*, chapter 50) is refined by finding
+the least (or greatest) distance given by
+[geocent_ecl_pos()](./fn.geocent_ecl_pos.html) within six days of it.
+Because the distance changes slowly near an apsis, the time is good only
+to some minutes, but the distance is good to some kilometers.
+
+# Arguments
+
+* `date` : Date of interest, close to the passage
+* `apsis`: The [Apsis](./enum.Apsis.html)
+**/
+pub fn time_of_apsis(date: &time::Date, apsis: &Apsis) -> (f64, f64) {
+
+    let k = 13.2555 * (time::decimal_year(date) - 1999.97);
+    let (k, sign) = match *apsis {
+        Apsis::Perigee => (k.round(), 1.0),
+        Apsis::Apogee  => ((k - 0.5).round() + 0.5, -1.0),
+    };
+    let T = k / 1325.55;
+
+    let JD_mn =
+        2451534.6698
+      + k * 27.55454989
+      + T * T * Horner_eval!(
+            T,
+           -0.0006691,
+           -0.000001098,
+            0.0000000052
+        );
+
+    let f = |JD: f64| sign * geocent_ecl_pos(JD).1;
+    let JD = search::scan_min(f, JD_mn - 6.0, JD_mn + 6.0, 48);
+
+    (JD, sign * f(JD))
+
+}
+
+/// Represents a direction of the Moon's greatest declination
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DecExtreme {
+    /// Greatest northern declination
+    North,
+    /// Greatest southern declination
+    South
+}
+
+/**
+Computes the time and value of the Moon's greatest northern or southern
+declination
+
+# Returns
+
+`(JD, dec)`
+
+* `JD` : Julian (Ephemeris) day of the greatest declination that is
+         closest to `date`
+* `dec`: Apparent declination of the Moon at the time
+         *| in radians*
+
+The time of the mean extreme (*Meeus*, chapter 52) is refined by finding
+the greatest (or least) declination given by
+[apprnt_eq_pos()](./fn.apprnt_eq_pos.html) within three days of it.
+Over the 18.6 year cycle of the Moon's nodes, the extremes range from
+about 18.3° (the minor lunar standstill) to about 28.7° (the major
+lunar standstill).
+
+# Arguments
+
+* `date`       : Date of interest, close to the extreme
+* `dec_extreme`: The [DecExtreme](./enum.DecExtreme.html)
+**/
+pub fn time_of_greatest_dec(date: &time::Date, dec_extreme: &DecExtreme) -> (f64, f64) {
+
+    let k = (13.3686 * (time::decimal_year(date) - 2000.03)).round();
+    let T = k / 1336.86;
+
+    let (JD0, sign) = match *dec_extreme {
+        DecExtreme::North => (2451562.5897, -1.0),
+        DecExtreme::South => (2451548.9289, 1.0),
+    };
+    let JD_mn =
+        JD0
+      + k * 27.321582247
+      + T * T * (0.000119804 - T*0.000000141);
+
+    let f = |JD: f64| sign * apprnt_eq_pos(JD).0.dec;
+    let JD = search::scan_min(f, JD_mn - 3.0, JD_mn + 3.0, 24);
+
+    (JD, sign * f(JD))
+
+}
@@ -24,6 +24,7 @@ THE SOFTWARE.
 
 use angle;
 use consts;
+use error::Error;
 
 /**
 Computes the true anomaly and radius vector of a body in a near-parabolic
@@ -45,7 +46,7 @@ orbit at a given time
 * `accuracy`: Desired accuracy for the results. For example, passing `0.000001`
               gives that much accuracy in radians and AU.
 **/
-pub fn true_anom_and_rad_vec<'a> (
+pub fn true_anom_and_rad_vec (
 
     t        : f64,
     T        : f64,
@@ -53,7 +54,7 @@ pub fn true_anom_and_rad_vec<'a> (
     q        : f64,
     accuracy : f64
 
-) -> Result<(f64, f64), &'a str>  {
+) -> Result<(f64, f64), Error> {
 
     let days_frm_perih = t - T;
 
@@ -73,7 +74,7 @@ pub fn true_anom_and_rad_vec<'a> (
 
     if days_frm_perih < 0.0 { s = -s; }
     if ecc != 1.0 {
-        let mut l = 0.0;
+        let mut l = 0;
         loop {
             let s0 = s;
             let mut z = 1.0;
@@ -88,14 +89,14 @@ pub fn true_anom_and_rad_vec<'a> (
                 let f = z1 * g1;
                 q3 += f;
                 if z > 50.0 || f.abs() > d1 {
-                    return Err("No convergence at orbit::near_parabolic::true_anom_and_rad_vec()");
+                    return Err(no_convergence(l));
                 }
                 if f.abs() <= accuracy { break; }
             }
 
-            l += 1.0;
-            if l > 50.0 {
-                return Err("No convergence at orbit::near_parabolic::true_anom_and_rad_vec()");
+            l += 1;
+            if l > 50 {
+                return Err(no_convergence(l));
             }
 
             loop {
@@ -114,3 +115,12 @@ pub fn true_anom_and_rad_vec<'a> (
     Ok((v, r))
 
 }
+
+fn no_convergence(iterations: u32) -> Error {
+
+    Error::NoConvergence {
+        function: "orbit::near_parabolic::true_anom_and_rad_vec",
+        iterations
+    }
+
+}
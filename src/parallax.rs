@@ -23,6 +23,7 @@ THE SOFTWARE.
 //! Find topocentric coordinates
 
 use angle;
+use angle::Radians;
 use coords;
 use planet;
 
@@ -74,7 +75,7 @@ pub fn topocent_eq_coords(eq_point: &coords::EqPoint,
     );
 
     let geocent_hr_angl = coords::hr_angl_frm_observer_long (
-        greenw_sidr, geograph_point.long, eq_point.asc
+        Radians(greenw_sidr), Radians(geograph_point.long), Radians(eq_point.asc)
     );
 
     let eq_hz_parllx_sin = eq_hz_parllx.sin();
@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [1.75347045673, 0.0, 0.0],
                     [0.03341656456, 4.66925680417, 6283.0758499914],
                     [0.00034894275, 4.62610241759, 12566.1516999828],
@@ -563,7 +562,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.5e-10, 3.18590558749, 45585.1728121874],
                     [4.9e-10, 2.44790934886, 13613.804277336],
                 ],
-            vec![
+            &[
                     [6283.31966747491, 0.0, 0.0],
                     [0.00206058863, 2.67823455584, 6283.0758499914],
                     [4.30343e-05, 2.63512650414, 12566.1516999828],
@@ -906,7 +905,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.4e-10, 1.40237993205, 14712.317116458],
                     [2.5e-10, 5.71466092822, 25934.1243310894],
                 ],
-            vec![
+            &[
                     [0.0005291887, 0.0, 0.0],
                     [8.719837e-05, 1.07209665242, 6283.0758499914],
                     [3.09125e-06, 0.86728818832, 12566.1516999828],
@@ -1050,7 +1049,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.1e-10, 3.05005267431, 17260.1546546904],
                     [1e-10, 4.93364992366, 12352.8526045448],
                 ],
-            vec![
+            &[
                     [2.89226e-06, 5.84384198723, 6283.0758499914],
                     [3.4955e-07, 0.0, 0.0],
                     [1.6819e-07, 5.48766912348, 12566.1516999828],
@@ -1074,7 +1073,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [5e-11, 3.76879847273, 12036.4607348882],
                     [5e-11, 4.28412873331, 6275.9623029906],
                 ],
-            vec![
+            &[
                     [1.14084e-06, 3.14159265359, 0.0],
                     [7.717e-08, 4.13446589358, 6283.0758499914],
                     [7.65e-09, 3.83803776214, 12566.1516999828],
@@ -1087,7 +1086,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8e-11, 2.84160570605, 161000.6857376741],
                     [2e-11, 0.54912904658, 6438.4962494256],
                 ],
-            vec![
+            &[
                     [8.78e-09, 3.14159265359, 0.0],
                     [1.72e-09, 2.7657906951, 6283.0758499914],
                     [5e-10, 2.01353298182, 155.4203994342],
@@ -1095,8 +1094,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [5e-11, 1.75600058765, 18849.2275499742],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [2.7962e-06, 3.19870156017, 84334.66158130829],
                     [1.01643e-06, 5.42248619256, 5507.5532386674],
                     [8.0445e-07, 3.88013204458, 5223.6939198022],
@@ -1282,7 +1281,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [3.9e-10, 3.28500401343, 71768.50988132549],
                     [3.9e-10, 3.1123991069, 96900.81328129109],
                 ],
-            vec![
+            &[
                     [9.03e-08, 3.8972906189, 5507.5532386674],
                     [6.177e-08, 1.73038850355, 5223.6939198022],
                     [3.8e-08, 5.24404145734, 2352.8661537718],
@@ -1383,7 +1382,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.8e-10, 1.58348238359, 2118.7638603784],
                     [1.9e-10, 0.85407021371, 14712.317116458],
                 ],
-            vec![
+            &[
                     [1.662e-08, 1.62703209173, 84334.66158130829],
                     [4.92e-09, 2.41382223971, 1047.7473117547],
                     [3.44e-09, 2.24353004539, 5507.5532386674],
@@ -1434,7 +1433,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.3e-10, 0.98720797401, 5729.506447149],
                     [9e-11, 5.94191743597, 7632.9432596502],
                 ],
-            vec![
+            &[
                     [1.1e-10, 0.23877262399, 7860.4193924392],
                     [9e-11, 1.16069982609, 5507.5532386674],
                     [8e-11, 1.65357552925, 5884.9268465832],
@@ -1447,7 +1446,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [6e-11, 5.40160929468, 1577.3435424478],
                     [7e-11, 2.73399865247, 6309.3741697912],
                 ],
-            vec![
+            &[
                     [4e-11, 0.79662198849, 6438.4962494256],
                     [5e-11, 0.84308705203, 1047.7473117547],
                     [5e-11, 0.05711572303, 84334.66158130829],
@@ -1455,8 +1454,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [3e-11, 2.89822201212, 6127.6554505572],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [1.00013988799, 0.0, 0.0],
                     [0.01670699626, 3.09846350771, 6283.0758499914],
                     [0.00013956023, 3.0552460962, 12566.1516999828],
@@ -1984,7 +1983,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4e-10, 3.30603243754, 23536.11695768099],
                     [5e-10, 6.15760345261, 78051.34191383338],
                 ],
-            vec![
+            &[
                     [0.00103018608, 1.10748969588, 6283.0758499914],
                     [1.721238e-05, 1.06442301418, 12566.1516999828],
                     [7.02215e-06, 3.14159265359, 0.0],
@@ -2278,7 +2277,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.9e-10, 2.54227398241, 77736.78343050249],
                     [2e-10, 5.91915117116, 48739.859897083],
                 ],
-            vec![
+            &[
                     [4.359385e-05, 5.78455133738, 6283.0758499914],
                     [1.23633e-06, 5.57934722157, 12566.1516999828],
                     [1.2341e-07, 3.14159265359, 0.0],
@@ -2419,7 +2418,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9e-11, 2.04999402381, 22003.9146348698],
                     [9e-11, 4.91488110218, 213.299095438],
                 ],
-            vec![
+            &[
                     [1.44595e-06, 4.27319435148, 6283.0758499914],
                     [6.729e-08, 3.91697608662, 12566.1516999828],
                     [7.74e-09, 0.0, 0.0],
@@ -2448,7 +2447,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [5e-11, 2.30961231391, 12036.4607348882],
                     [5e-11, 3.71102966917, 6290.1893969922],
                 ],
-            vec![
+            &[
                     [3.858e-08, 2.56384387339, 6283.0758499914],
                     [3.06e-09, 2.2676950123, 12566.1516999828],
                     [5.3e-10, 3.44031471924, 5573.1428014331],
@@ -2460,11 +2459,10 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [6e-11, 3.81514213664, 149854.4001348079],
                     [3e-11, 1.28175749811, 6286.5989683404],
                 ],
-            vec![
+            &[
                     [8.6e-10, 1.21579741687, 6283.0758499914],
                     [1.2e-10, 0.65617264033, 12566.1516999828],
                     [1e-11, 0.38068797142, 18849.2275499742],
                 ]
             ]
-    ]
-}
+    ];
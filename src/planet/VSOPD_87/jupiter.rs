@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [0.59954691495, 0.0, 0.0],
                     [0.09695898711, 5.06191793105, 529.6909650946],
                     [0.00573610145, 1.44406205976, 7.1135470008],
@@ -764,7 +763,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.5e-10, 1.86831145784, 25028.521211385],
                     [7.9e-10, 2.9060220289, 2114.8317071153],
                 ],
-            vec![
+            &[
                     [529.93480757497, 0.0, 0.0],
                     [0.00489741194, 4.22066689928, 529.6909650946],
                     [0.00228918538, 6.02647464016, 7.1135470008],
@@ -1135,7 +1134,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8e-10, 0.045397201, 1080.7225711916],
                     [7.5e-10, 4.26526686574, 1058.4187223427],
                 ],
-            vec![
+            &[
                     [0.00047233598, 4.32148323554, 7.1135470008],
                     [0.00030629053, 2.93021440216, 529.6909650946],
                     [0.0003896555, 0.0, 0.0],
@@ -1328,7 +1327,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.1e-10, 1.48896790758, 1567.7322542814],
                     [8.6e-10, 4.34444949905, 1069.6768709277],
                 ],
-            vec![
+            &[
                     [6.501665e-05, 2.59862880482, 7.1135470008],
                     [1.356524e-05, 1.34635886411, 529.6909650946],
                     [4.70716e-06, 2.47503977883, 14.2270940016],
@@ -1439,7 +1438,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.9e-10, 1.8853315322, 934.9485149682],
                     [7.7e-10, 3.80503143236, 1603.2999892854],
                 ],
-            vec![
+            &[
                     [6.69483e-06, 0.8528242109, 7.1135470008],
                     [9.9961e-07, 0.74258947751, 14.2270940016],
                     [1.14019e-06, 3.14159265359, 0.0],
@@ -1486,7 +1485,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.5e-10, 5.023172562, 1155.361157407],
                     [7.5e-10, 3.13198879608, 632.7837393132],
                 ],
-            vec![
+            &[
                     [4.9577e-07, 5.25658966184, 7.1135470008],
                     [1.5761e-07, 5.25126837478, 14.2270940016],
                     [4.343e-08, 0.01461869263, 536.8045120954],
@@ -1499,8 +1498,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9e-10, 0.2117811971, 529.6909650946],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.02268615703, 3.55852606718, 529.6909650946],
                     [0.00109971634, 3.90809347389, 1059.3819301892],
                     [0.00110090358, 0.0, 0.0],
@@ -1751,7 +1750,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.4e-10, 3.86865238736, 3067.9394693482],
                     [9.5e-10, 1.66362447044, 1151.4290041439],
                 ],
-            vec![
+            &[
                     [0.00177351787, 5.70166488486, 529.6909650946],
                     [3.230171e-05, 5.7794161934, 1059.3819301892],
                     [3.081364e-05, 5.47464296527, 522.5774180938],
@@ -1894,7 +1893,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.2e-10, 5.57619428876, 530.44172462],
                     [7.8e-10, 5.97323507836, 1585.8915015461],
                 ],
-            vec![
+            &[
                     [8.094051e-05, 1.46322843658, 529.6909650946],
                     [7.42415e-06, 0.95691639003, 522.5774180938],
                     [8.13244e-06, 3.14159265359, 0.0],
@@ -1977,7 +1976,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.9e-10, 1.22926014128, 1898.3512179396],
                     [8e-10, 0.62129648755, 831.8557407496],
                 ],
-            vec![
+            &[
                     [2.51624e-06, 3.38087923084, 529.6909650946],
                     [1.21738e-06, 2.733118372, 522.5774180938],
                     [4.8694e-07, 1.03689996685, 536.8045120954],
@@ -2021,7 +2020,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.8e-10, 3.26874502411, 213.299095438],
                     [9.8e-10, 2.00704668688, 1574.8458012822],
                 ],
-            vec![
+            &[
                     [1.505e-07, 4.52956999637, 522.5774180938],
                     [5.37e-08, 4.47427159142, 529.6909650946],
                     [4.456e-08, 5.43908581047, 536.8045120954],
@@ -2035,7 +2034,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.42e-09, 5.69936472988, 7.1135470008],
                     [1.12e-09, 1.16718383135, 14.2270940016],
                 ],
-            vec![
+            &[
                     [1.445e-08, 0.09198554072, 522.5774180938],
                     [3.68e-09, 0.00874408003, 515.463871093],
                     [3.04e-09, 3.27902945138, 536.8045120954],
@@ -2043,8 +2042,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.5e-10, 1.29305954542, 543.9180590962],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [5.20887429471, 0.0, 0.0],
                     [0.2520932702, 3.49108640015, 529.6909650946],
                     [0.00610599902, 3.84115365602, 1059.3819301892],
@@ -2791,7 +2790,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.74e-09, 0.71146352197, 2108.4689196399],
                     [5.09e-09, 5.53328407404, 1128.534454464],
                 ],
-            vec![
+            &[
                     [0.01271801596, 2.64937511122, 529.6909650946],
                     [0.00061661771, 3.00076251018, 1059.3819301892],
                     [0.00053443592, 3.89717644226, 522.5774180938],
@@ -3174,7 +3173,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [3.92e-09, 1.86527946688, 885.4397106664],
                     [4.16e-09, 3.81408093105, 3156.8051495652],
                 ],
-            vec![
+            &[
                     [0.00079644833, 1.35865896596, 529.6909650946],
                     [8.251618e-05, 5.77773935444, 522.5774180938],
                     [7.029864e-05, 3.27476965833, 536.8045120954],
@@ -3366,7 +3365,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4e-09, 3.13887720912, 540.7366653585],
                     [3.78e-09, 0.8612245094, 525.0250986486],
                 ],
-            vec![
+            &[
                     [3.519257e-05, 6.05800633846, 529.6909650946],
                     [1.073239e-05, 1.6732134576, 536.8045120954],
                     [9.15666e-06, 1.41329676116, 522.5774180938],
@@ -3466,7 +3465,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.06e-09, 3.93940190897, 316.3918696566],
                     [5.06e-09, 0.18719982992, 10.2949407385],
                 ],
-            vec![
+            &[
                     [1.28628e-06, 0.08419309557, 536.8045120954],
                     [1.13458e-06, 4.24858855779, 529.6909650946],
                     [8.265e-07, 3.29754909408, 522.5774180938],
@@ -3514,7 +3513,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.91e-09, 1.52912023181, 405.2575498736],
                     [5.21e-09, 0.24011424451, 433.7117378768],
                 ],
-            vec![
+            &[
                     [1.1188e-07, 4.75249399945, 536.8045120954],
                     [4.255e-08, 5.9151622917, 522.5774180938],
                     [2.079e-08, 5.56781555864, 515.463871093],
@@ -3526,5 +3525,4 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.033e-08, 4.50671820436, 529.6909650946],
                 ]
             ]
-    ]
-}
+    ];
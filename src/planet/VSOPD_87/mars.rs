@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [6.20347711583, 0.0, 0.0],
                     [0.186563681, 5.05037100303, 3340.6124266998],
                     [0.01108216792, 5.40099836958, 6681.2248533996],
@@ -1221,7 +1220,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.5e-10, 2.33470159894, 3123.7902129128],
                     [3.9e-10, 4.91767933978, 8034.6153814074],
                 ],
-            vec![
+            &[
                     [3340.85627474342, 0.0, 0.0],
                     [0.01458227051, 3.60426053609, 3340.6124266998],
                     [0.00164901343, 3.92631250962, 6681.2248533996],
@@ -1909,7 +1908,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.4e-10, 0.20355912395, 3229.4257844122],
                     [3.2e-10, 3.37195631109, 2284.7536148596],
                 ],
-            vec![
+            &[
                     [0.00058015791, 2.04979463279, 3340.6124266998],
                     [0.00054187645, 0.0, 0.0],
                     [0.00013908426, 2.45742359888, 6681.2248533996],
@@ -2221,7 +2220,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.7e-10, 2.84467149903, 3344.4937620578],
                     [2e-10, 5.41519706836, 3205.5473466644],
                 ],
-            vec![
+            &[
                     [1.482423e-05, 0.44434694876, 3340.6124266998],
                     [6.62095e-06, 0.88469178686, 6681.2248533996],
                     [1.88268e-06, 1.28799982497, 10021.8372800994],
@@ -2352,7 +2351,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.2e-10, 3.28248484262, 8671.9698704406],
                     [1.4e-10, 0.23027665815, 3767.2106175758],
                 ],
-            vec![
+            &[
                     [1.13969e-06, 3.14159265359, 0.0],
                     [2.8725e-07, 5.63662412043, 6681.2248533996],
                     [2.4447e-07, 5.13868481454, 3340.6124266998],
@@ -2390,7 +2389,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9e-11, 6.15390464542, 6489.776587288],
                     [7e-11, 4.23894194106, 4535.0594369244],
                 ],
-            vec![
+            &[
                     [7.1e-09, 4.04089996521, 6681.2248533996],
                     [8.68e-09, 3.14159265359, 0.0],
                     [5.1e-09, 4.49214901625, 10021.8372800994],
@@ -2408,8 +2407,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.2e-10, 1.93859256739, 6525.8044539654],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.03197134986, 3.76832042432, 3340.6124266998],
                     [0.00298033234, 4.10616996243, 6681.2248533996],
                     [0.00289104742, 0.0, 0.0],
@@ -2852,7 +2851,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.3e-10, 0.51983815091, 16489.763038061],
                     [3.6e-10, 0.84102576439, 23937.856389741],
                 ],
-            vec![
+            &[
                     [0.00350068845, 5.36847836211, 3340.6124266998],
                     [0.0001411603, 3.14159265359, 0.0],
                     [9.670755e-05, 5.47877786506, 6681.2248533996],
@@ -3141,7 +3140,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.8e-10, 1.30366587075, 8439.8779318164],
                     [3.2e-10, 2.13597148493, 9468.267877257],
                 ],
-            vec![
+            &[
                     [0.0001672669, 0.60221392419, 3340.6124266998],
                     [4.986799e-05, 3.14159265359, 0.0],
                     [3.02141e-06, 5.55871276021, 6681.2248533996],
@@ -3273,7 +3272,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.8e-10, 6.22706341047, 9381.9399937854],
                     [1.5e-10, 0.54135050421, 640.8776073822],
                 ],
-            vec![
+            &[
                     [6.06506e-06, 1.98050633529, 3340.6124266998],
                     [4.2611e-07, 0.0, 0.0],
                     [1.3652e-07, 1.795882288, 6681.2248533996],
@@ -3316,7 +3315,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.1e-10, 0.43817745316, 1059.3819301892],
                     [1.4e-10, 1.06654064248, 6836.6452528338],
                 ],
-            vec![
+            &[
                     [1.1334e-07, 3.45724352586, 3340.6124266998],
                     [1.3369e-07, 0.0, 0.0],
                     [7.44e-09, 0.50445805257, 6681.2248533996],
@@ -3329,7 +3328,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.3e-10, 2.06957065662, 13362.4497067992],
                     [1e-10, 0.959895956, 3894.1818295422],
                 ],
-            vec![
+            &[
                     [4.57e-09, 4.86794125358, 3340.6124266998],
                     [5.3e-10, 5.30547050586, 6681.2248533996],
                     [1.2e-10, 5.75114070583, 10021.8372800994],
@@ -3337,8 +3336,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7e-11, 0.0, 0.0],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [1.53033488276, 0.0, 0.0],
                     [0.14184953153, 3.47971283519, 3340.6124266998],
                     [0.00660776357, 3.81783442097, 6681.2248533996],
@@ -4458,7 +4457,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [5.9e-10, 4.71734799803, 6283.0931036436],
                     [6.8e-10, 5.74655335863, 987.746272928],
                 ],
-            vec![
+            &[
                     [0.0110743334, 2.0325052495, 3340.6124266998],
                     [0.00103175886, 2.37071845682, 6681.2248533996],
                     [0.000128772, 0.0, 0.0],
@@ -5056,7 +5055,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.1e-10, 4.26832466355, 21000.9158907568],
                     [3.8e-10, 0.73199792046, 26084.0218062162],
                 ],
-            vec![
+            &[
                     [0.00044242247, 0.47930603943, 3340.6124266998],
                     [8.138042e-05, 0.86998398093, 6681.2248533996],
                     [1.274915e-05, 1.22594050809, 10021.8372800994],
@@ -5371,7 +5370,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.5e-10, 1.40880282063, 8186.5126624926],
                     [2.4e-10, 4.96463811748, 26087.9031415742],
                 ],
-            vec![
+            &[
                     [1.113107e-05, 5.14987350142, 3340.6124266998],
                     [4.24446e-06, 5.61343766478, 6681.2248533996],
                     [1.00044e-06, 5.99726827028, 10021.8372800994],
@@ -5484,7 +5483,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.6e-10, 0.6538950344, 3340.545116397],
                     [1.6e-10, 5.28427106012, 3333.498879699],
                 ],
-            vec![
+            &[
                     [1.9552e-07, 3.58211650473, 3340.6124266998],
                     [1.6323e-07, 4.05116076923, 6681.2248533996],
                     [5.848e-08, 4.46383962094, 10021.8372800994],
@@ -5514,7 +5513,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.4e-10, 0.76591550754, 3894.1818295422],
                     [1.1e-10, 5.47381242145, 3738.761430108],
                 ],
-            vec![
+            &[
                     [4.76e-09, 2.47617204701, 6681.2248533996],
                     [2.68e-09, 2.91510547706, 10021.8372800994],
                     [1.15e-09, 1.76888962311, 3340.6124266998],
@@ -5526,5 +5525,4 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.2e-10, 3.14159265359, 0.0],
                 ]
             ]
-    ]
-}
+    ];
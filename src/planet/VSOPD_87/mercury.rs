@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [4.40250710144, 0.0, 0.0],
                     [0.40989414976, 1.48302034194, 26087.9031415742],
                     [0.05046294199, 4.4778548954, 52175.8062831484],
@@ -1384,7 +1383,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4e-11, 2.66996288682, 25572.43927048119],
                     [5e-11, 1.14175711692, 188898.39784101077],
                 ],
-            vec![
+            &[
                     [26088.14706222746, 0.0, 0.0],
                     [0.01126007832, 6.21703970996, 26087.9031415742],
                     [0.00303471395, 3.05565472363, 52175.8062831484],
@@ -2225,7 +2224,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2e-11, 5.10682926241, 159969.99379441058],
                     [2e-11, 3.49751807681, 259819.64948555277],
                 ],
-            vec![
+            &[
                     [0.00053049845, 0.0, 0.0],
                     [0.00016903658, 4.69072300649, 26087.9031415742],
                     [7.396711e-05, 1.34735624669, 52175.8062831484],
@@ -2622,7 +2621,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1e-11, 3.26520760555, 240452.46032331997],
                     [1e-11, 5.52286605071, 52125.80966124419],
                 ],
-            vec![
+            &[
                     [1.88077e-06, 0.03466830117, 52175.8062831484],
                     [1.42152e-06, 3.125054526, 26087.9031415742],
                     [9.6877e-07, 3.00378171915, 78263.70942472259],
@@ -2777,7 +2776,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1e-11, 5.84438975852, 105307.21230790539],
                     [1e-11, 2.82906439989, 24498.8302462904],
                 ],
-            vec![
+            &[
                     [1.14078e-06, 3.14159265359, 0.0],
                     [3.247e-08, 2.02848007619, 26087.9031415742],
                     [1.914e-08, 1.41731803758, 78263.70942472259],
@@ -2807,7 +2806,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 0.40939402954, 24498.8302462904],
                     [0.0, 3.30678220146, 365230.6439820388],
                 ],
-            vec![
+            &[
                     [8.77e-09, 3.14159265359, 0.0],
                     [5.9e-10, 3.37513289692, 52175.8062831484],
                     [4.2e-10, 0.02433099382, 78263.70942472259],
@@ -2823,8 +2822,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 2.11315496491, 313054.83769889036],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.11737528962, 1.98357498767, 26087.9031415742],
                     [0.02388076996, 5.03738959685, 52175.8062831484],
                     [0.01222839532, 3.14159265359, 0.0],
@@ -3644,7 +3643,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4e-11, 1.34781477964, 106684.80895916879],
                     [4e-11, 2.34997151211, 177287.84588263658],
                 ],
-            vec![
+            &[
                     [0.00429151362, 3.50169780393, 26087.9031415742],
                     [0.00146233668, 3.14159265359, 0.0],
                     [0.00022675295, 0.0151536688, 52175.8062831484],
@@ -4140,7 +4139,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [3e-11, 0.6555617912, 1692.1656695024],
                     [2e-11, 1.65727778641, 163766.0944410452],
                 ],
-            vec![
+            &[
                     [0.00011830934, 4.79065585784, 26087.9031415742],
                     [1.913516e-05, 0.0, 0.0],
                     [1.044801e-05, 1.21216540536, 52175.8062831484],
@@ -4372,7 +4371,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1e-11, 1.12941846578, 60055.89543648739],
                     [1e-11, 1.16964771504, 134991.4692049298],
                 ],
-            vec![
+            &[
                     [2.35423e-06, 0.35387524604, 26087.9031415742],
                     [1.60537e-06, 0.0, 0.0],
                     [1.8904e-07, 4.36275460261, 52175.8062831484],
@@ -4427,7 +4426,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1e-11, 2.44816565966, 26084.0218062162],
                     [0.0, 3.45284067711, 105460.99111839019],
                 ],
-            vec![
+            &[
                     [4.276e-08, 1.74579932115, 26087.9031415742],
                     [1.023e-08, 3.14159265359, 0.0],
                     [4.25e-09, 4.03419509143, 52175.8062831484],
@@ -4444,7 +4443,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 1.44918128781, 24978.5245894808],
                     [0.0, 4.1804627869, 313054.83769889036],
                 ],
-            vec![
+            &[
                     [1.06e-09, 3.94555784256, 26087.9031415742],
                     [7.5e-10, 3.14159265359, 0.0],
                     [2.2e-10, 1.30514874546, 52175.8062831484],
@@ -4457,8 +4456,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 5.96962108614, 234791.12827416777],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.39528271652, 0.0, 0.0],
                     [0.07834131817, 6.19233722599, 26087.9031415742],
                     [0.00795525557, 2.95989690096, 52175.8062831484],
@@ -5675,7 +5674,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2e-11, 3.05277164813, 225.8292684102],
                     [1e-11, 4.33602746737, 4601.950118963],
                 ],
-            vec![
+            &[
                     [0.00217347739, 4.65617158663, 26087.9031415742],
                     [0.00044141826, 1.42385543975, 52175.8062831484],
                     [0.00010094479, 4.47466326316, 78263.70942472259],
@@ -6388,7 +6387,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1e-11, 4.92352598929, 51596.1186961496],
                     [1e-11, 6.10538330268, 181659.72224941078],
                 ],
-            vec![
+            &[
                     [3.117867e-05, 3.08231840296, 26087.9031415742],
                     [1.245396e-05, 6.15183317423, 52175.8062831484],
                     [4.24822e-06, 2.9258335296, 78263.70942472259],
@@ -6716,7 +6715,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 3.99449121788, 131395.11544947958],
                     [0.0, 5.65446547429, 80596.9058175946],
                 ],
-            vec![
+            &[
                     [3.2676e-07, 1.67971635359, 26087.9031415742],
                     [2.4166e-07, 4.63403168997, 52175.8062831484],
                     [1.2133e-07, 1.38983781545, 78263.70942472259],
@@ -6837,7 +6836,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 1.86829495425, 48733.23133818299],
                     [0.0, 5.77443179008, 105410.99449648599],
                 ],
-            vec![
+            &[
                     [3.94e-09, 0.3673540384, 26087.9031415742],
                     [3.87e-09, 3.18568771507, 52175.8062831484],
                     [2.7e-09, 6.16983616444, 78263.70942472259],
@@ -6857,7 +6856,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 2.93310416266, 27197.2816936676],
                     [0.0, 2.89897922039, 103242.2340142034],
                 ],
-            vec![
+            &[
                     [6e-11, 3.98900269603, 26087.9031415742],
                     [6e-11, 1.55248278782, 52175.8062831484],
                     [5e-11, 4.65461721116, 78263.70942472259],
@@ -6870,5 +6869,4 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [0.0, 4.0026706421, 234791.12827416777],
                 ]
             ]
-    ]
-}
+    ];
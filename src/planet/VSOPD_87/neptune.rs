@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [5.31188633047, 0.0, 0.0],
                     [0.01798475509, 2.9010127305, 38.1330356378],
                     [0.01019727662, 0.4858092366, 1.4844727083],
@@ -427,7 +426,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.4e-10, 4.92511651321, 1549.4553869377],
                     [7.2e-10, 5.06852406179, 249.9476583675],
                 ],
-            vec![
+            &[
                     [38.37687716731, 0.0, 0.0],
                     [0.00016604187, 4.86319129565, 1.4844727083],
                     [0.00015807148, 2.27923488532, 38.1330356378],
@@ -612,7 +611,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.4e-10, 2.64687252518, 536.8045120954],
                     [7.4e-10, 3.90678924318, 1019.7644218431],
                 ],
-            vec![
+            &[
                     [0.00053892649, 0.0, 0.0],
                     [2.81251e-06, 1.19084538887, 38.1330356378],
                     [2.95693e-06, 1.85520292248, 1.4844727083],
@@ -671,7 +670,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.04e-09, 6.03262825314, 529.6909650946],
                     [7.6e-10, 0.20932812381, 453.424893819],
                 ],
-            vec![
+            &[
                     [3.1254e-07, 0.0, 0.0],
                     [1.2461e-07, 6.04431418812, 1.4844727083],
                     [1.4541e-07, 1.35337075856, 76.2660712756],
@@ -688,16 +687,16 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.6e-09, 5.63111039032, 114.3991069134],
                     [1.49e-09, 3.09327713923, 35.1640902212],
                 ],
-            vec![
+            &[
                     [1.13998e-06, 3.14159265359, 0.0],
                     [6.05e-09, 3.18211885677, 76.2660712756],
                 ],
-            vec![
+            &[
                     [8.74e-09, 3.14159265359, 0.0],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.03088622933, 1.44104372626, 38.1330356378],
                     [0.00027780087, 5.91271882843, 76.2660712756],
                     [0.00027623609, 0.0, 0.0],
@@ -871,7 +870,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.5e-10, 0.23453373368, 63.7358983034],
                     [7.1e-10, 1.5196198969, 488.5889840402],
                 ],
-            vec![
+            &[
                     [0.00227279214, 3.8079308987, 38.1330356378],
                     [1.80312e-05, 1.97576485377, 76.2660712756],
                     [1.385733e-05, 4.82555548018, 36.6485629295],
@@ -955,7 +954,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.2e-10, 3.18401661435, 42.3258213318],
                     [8.4e-10, 5.51669920239, 8.0767548473],
                 ],
-            vec![
+            &[
                     [9.690766e-05, 5.57123750291, 38.1330356378],
                     [7.8815e-07, 3.62705474219, 76.2660712756],
                     [7.1523e-07, 0.4547668858, 36.6485629295],
@@ -982,7 +981,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.5e-10, 0.43712705655, 4.4534181249],
                     [1.04e-09, 6.12597614674, 144.1465711632],
                 ],
-            vec![
+            &[
                     [2.73423e-06, 1.01688979072, 38.1330356378],
                     [2.274e-08, 2.36805657126, 36.6485629295],
                     [2.029e-08, 5.33364321342, 76.2660712756],
@@ -993,15 +992,15 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.55e-09, 3.62376309338, 35.1640902212],
                     [1.57e-09, 3.9419536961, 1.4844727083],
                 ],
-            vec![
+            &[
                     [5.728e-08, 2.66872693322, 38.1330356378],
                 ],
-            vec![
+            &[
                     [1.13e-09, 4.70646877989, 38.1330356378],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [30.07013206102, 0.0, 0.0],
                     [0.2706225949, 1.3299945893, 38.1330356378],
                     [0.01691764281, 3.25186138896, 36.6485629295],
@@ -1610,7 +1609,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.266e-08, 5.91286000054, 3.4902102784],
                     [2.231e-08, 1.45038594906, 196.5067008026],
                 ],
-            vec![
+            &[
                     [0.00236338502, 0.70498011235, 38.1330356378],
                     [0.00013220279, 3.32015499895, 1.4844727083],
                     [8.621863e-05, 6.2162895163, 35.1640902212],
@@ -1862,7 +1861,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.962e-08, 1.74151265966, 2080.6308247406],
                     [2.621e-08, 1.74442251671, 35.212274331],
                 ],
-            vec![
+            &[
                     [4.247412e-05, 5.89910679117, 38.1330356378],
                     [2.1757e-06, 0.3458182908, 1.4844727083],
                     [1.63025e-06, 2.2387294713, 168.0525127994],
@@ -1936,7 +1935,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.48e-08, 4.59623030219, 693.5506922],
                     [2.253e-08, 0.74334306011, 976.0023119128],
                 ],
-            vec![
+            &[
                     [1.66297e-06, 4.55243893489, 38.1330356378],
                     [2.238e-07, 3.94830879358, 168.0525127994],
                     [2.1348e-07, 2.86296778794, 182.279606801],
@@ -1960,7 +1959,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.874e-08, 6.1964334054, 815.0633461142],
                     [2.278e-08, 3.66579603119, 497.1870037493],
                 ],
-            vec![
+            &[
                     [4.227e-08, 2.40375758563, 477.3308354552],
                     [4.333e-08, 0.10459484545, 395.578702239],
                     [3.545e-08, 4.78431259422, 1028.3624415522],
@@ -1970,5 +1969,4 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.295e-08, 5.67776133184, 168.0525127994],
                 ]
             ]
-    ]
-}
+    ];
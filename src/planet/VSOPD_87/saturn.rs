@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [0.87401354029, 0.0, 0.0],
                     [0.1110765978, 3.96205090194, 213.299095438],
                     [0.01414150958, 4.58581515873, 7.1135470008],
@@ -1156,7 +1155,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.9e-10, 0.15018241445, 186.2117600641],
                     [7.2e-10, 5.15715918322, 110.1581371096],
                 ],
-            vec![
+            &[
                     [213.54295595986, 0.0, 0.0],
                     [0.01296855005, 1.82820544701, 213.299095438],
                     [0.00564347566, 2.88500136429, 7.1135470008],
@@ -1800,7 +1799,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.8e-10, 5.5981938746, 364.3467652809],
                     [7.9e-10, 3.53267171729, 969.6224780949],
                 ],
-            vec![
+            &[
                     [0.00116441181, 1.17987850633, 7.1135470008],
                     [0.00091920844, 0.07425261094, 213.299095438],
                     [0.00090592251, 0.0, 0.0],
@@ -2123,7 +2122,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.3e-10, 4.85923277221, 2118.7638603784],
                     [7.1e-10, 3.64551577433, 198.321241911],
                 ],
-            vec![
+            &[
                     [0.00016038734, 5.73945377424, 7.1135470008],
                     [4.249793e-05, 4.58539675603, 213.299095438],
                     [1.906524e-05, 4.76082050205, 220.4126424388],
@@ -2273,7 +2272,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.2e-10, 3.74361312157, 1485.9801210652],
                     [7.6e-10, 3.33892447677, 195.8906076987],
                 ],
-            vec![
+            &[
                     [1.661894e-05, 3.99826248978, 7.1135470008],
                     [2.57107e-06, 2.98436499013, 220.4126424388],
                     [2.36344e-06, 3.90241428075, 14.2270940016],
@@ -2343,7 +2342,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.6e-10, 2.93247663741, 224.3447957019],
                     [8.8e-10, 4.48383632427, 423.4167971383],
                 ],
-            vec![
+            &[
                     [1.23615e-06, 2.25923345732, 7.1135470008],
                     [3.419e-07, 2.16250652689, 14.2270940016],
                     [2.7546e-07, 1.19868150215, 220.4126424388],
@@ -2373,8 +2372,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.02e-09, 0.49450039082, 117.3198682202],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.0433067804, 3.60284428399, 213.299095438],
                     [0.00240348303, 2.8523848939, 426.598190876],
                     [0.00084745939, 0.0, 0.0],
@@ -2876,7 +2875,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.2e-10, 4.85259171933, 392.6579409322],
                     [8.3e-10, 0.11133738383, 402.2191684878],
                 ],
-            vec![
+            &[
                     [0.00397554998, 5.33289992556, 213.299095438],
                     [0.00049478641, 3.14159265359, 0.0],
                     [0.00018571607, 6.09919206378, 426.598190876],
@@ -3138,7 +3137,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.1e-10, 5.97938003596, 556.5176680376],
                     [8.7e-10, 2.71469794199, 617.8058857862],
                 ],
-            vec![
+            &[
                     [0.00020629977, 0.50482422817, 213.299095438],
                     [3.719555e-05, 3.99833475829, 206.1855484372],
                     [1.627158e-05, 6.181899395, 220.4126424388],
@@ -3251,7 +3250,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.1e-10, 1.16732337173, 217.964961884],
                     [7.2e-10, 5.47328223678, 337.732510659],
                 ],
-            vec![
+            &[
                     [6.66252e-06, 1.99006340181, 213.299095438],
                     [6.3235e-06, 5.69778316807, 206.1855484372],
                     [3.98051e-06, 0.0, 0.0],
@@ -3311,7 +3310,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.5e-10, 1.18898817378, 728.762966531],
                     [8.4e-10, 4.10158366806, 224.3447957019],
                 ],
-            vec![
+            &[
                     [8.0384e-07, 1.11918414679, 206.1855484372],
                     [3.166e-07, 3.12218745098, 213.299095438],
                     [1.7143e-07, 2.48073200414, 220.4126424388],
@@ -3339,7 +3338,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.15e-09, 5.03884718594, 117.3198682202],
                     [1.15e-09, 0.44589613974, 110.2063212194],
                 ],
-            vec![
+            &[
                     [7.895e-08, 2.81927558645, 206.1855484372],
                     [1.014e-08, 0.5118721027, 220.4126424388],
                     [7.72e-09, 2.99484124049, 199.0720014364],
@@ -3353,8 +3352,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.9e-10, 0.5839686453, 213.299095438],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [9.55758135801, 0.0, 0.0],
                     [0.52921382465, 2.39226219733, 213.299095438],
                     [0.01873679934, 5.23549605091, 206.1855484372],
@@ -4561,7 +4560,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.27e-09, 2.59300433753, 232.942815411],
                     [7.35e-09, 3.05650026582, 66.9172920411],
                 ],
-            vec![
+            &[
                     [0.06182981282, 0.25843515034, 213.299095438],
                     [0.00506577574, 0.71114650941, 206.1855484372],
                     [0.00341394136, 5.7963577396, 426.598190876],
@@ -5202,7 +5201,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.39e-09, 1.85473712038, 5062.2699145056],
                     [7.21e-09, 1.62872794201, 2200.5159935946],
                 ],
-            vec![
+            &[
                     [0.00436902464, 4.78671673044, 213.299095438],
                     [0.0007192276, 2.50069994874, 206.1855484372],
                     [0.00049766792, 4.9716815087, 220.4126424388],
@@ -5546,7 +5545,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.39e-09, 3.33688408107, 953.1077622329],
                     [7.45e-09, 6.22304530635, 1269.4996318895],
                 ],
-            vec![
+            &[
                     [0.00020315005, 3.02186626038, 213.299095438],
                     [8.923581e-05, 3.19144205755, 220.4126424388],
                     [6.908677e-05, 4.35174889353, 206.1855484372],
@@ -5705,7 +5704,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7.72e-09, 1.15596098579, 3053.7123753466],
                     [6.91e-09, 3.13193109668, 56.6223513026],
                 ],
-            vec![
+            &[
                     [1.20205e-05, 1.41499446465, 220.4126424388],
                     [7.07796e-06, 1.16153570102, 213.299095438],
                     [5.16121e-06, 6.2397356833, 206.1855484372],
@@ -5771,7 +5770,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [9.69e-09, 1.00708261792, 1045.1548361876],
                     [7.16e-09, 1.11042181341, 1169.5882514086],
                 ],
-            vec![
+            &[
                     [1.28612e-06, 5.91282565136, 220.4126424388],
                     [3.2273e-07, 0.69256228602, 7.1135470008],
                     [2.6698e-07, 5.91428528629, 227.5261894396],
@@ -5802,5 +5801,4 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8.37e-09, 5.04769794123, 124.433415221],
                 ]
             ]
-    ]
-}
+    ];
@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [5.48129294299, 0.0, 0.0],
                     [0.09260408252, 0.8910642153, 74.7815985673],
                     [0.01504247826, 3.62719262195, 1.4844727083],
@@ -951,7 +950,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.17e-09, 5.37802827323, 293.7097682978],
                     [1.36e-09, 2.80772094137, 374.4986936675],
                 ],
-            vec![
+            &[
                     [75.02543121646, 0.0, 0.0],
                     [0.00154458244, 5.24201658072, 74.7815985673],
                     [0.00024456413, 1.71255705309, 1.4844727083],
@@ -1379,7 +1378,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.67e-09, 2.7460416758, 69.1525242748],
                     [1.29e-09, 2.87574897902, 156.1554792736],
                 ],
-            vec![
+            &[
                     [0.00053033277, 0.0, 0.0],
                     [2.357636e-05, 2.26014661705, 74.7815985673],
                     [7.69129e-06, 4.52561041823, 11.0457002639],
@@ -1532,7 +1531,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.31e-09, 2.73236351123, 522.5774180938],
                     [1.42e-09, 5.03489222377, 536.8045120954],
                 ],
-            vec![
+            &[
                     [1.20936e-06, 0.02418789918, 74.7815985673],
                     [6.8064e-07, 4.12084267733, 3.9321532631],
                     [5.2828e-07, 2.3896406126, 11.0457002639],
@@ -1580,7 +1579,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.49e-09, 0.13764106563, 71.8126531507],
                     [1.56e-09, 2.90936922804, 153.4953503977],
                 ],
-            vec![
+            &[
                     [1.13855e-06, 3.14159265359, 0.0],
                     [5.599e-08, 4.57882424417, 74.7815985673],
                     [3.203e-08, 0.34623003207, 11.0457002639],
@@ -1589,12 +1588,12 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.71e-09, 3.80393539303, 149.5631971346],
                     [1.33e-09, 4.35519131657, 63.7358983034],
                 ],
-            vec![
+            &[
                     [8.73e-09, 3.14159265359, 0.0],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.01346277639, 2.61877810545, 74.7815985673],
                     [0.00062341405, 5.08111175856, 149.5631971346],
                     [0.00061601203, 3.14159265359, 0.0],
@@ -1879,7 +1878,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.2e-09, 4.08565783859, 154.979823106],
                     [1.33e-09, 3.16576403244, 262.4778313397],
                 ],
-            vec![
+            &[
                     [0.00206366162, 4.12394311407, 74.7815985673],
                     [8.56323e-05, 0.33819986165, 149.5631971346],
                     [1.725703e-05, 2.12193159895, 73.297125859],
@@ -2035,7 +2034,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.21e-09, 6.0823907637, 260.9933586314],
                     [1.54e-09, 4.18369977366, 39.3568759152],
                 ],
-            vec![
+            &[
                     [9.211656e-05, 5.80044305785, 74.7815985673],
                     [5.56926e-06, 0.0, 0.0],
                     [2.86265e-06, 2.17729776353, 149.5631971346],
@@ -2097,7 +2096,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.27e-09, 6.17908901556, 62.2514255951],
                     [1.16e-09, 6.26646620658, 220.4126424388],
                 ],
-            vec![
+            &[
                     [2.67832e-06, 1.25097888291, 74.7815985673],
                     [1.1048e-07, 3.14159265359, 0.0],
                     [6.154e-08, 4.00663614486, 149.5631971346],
@@ -2115,13 +2114,13 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.68e-09, 4.67745630044, 70.8494453042],
                     [1.7e-09, 2.93672195979, 11.0457002639],
                 ],
-            vec![
+            &[
                     [5.719e-08, 2.85499529315, 74.7815985673],
                     [3e-09, 3.14159265359, 0.0],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [19.21264847881, 0.0, 0.0],
                     [0.88784984055, 5.60377526994, 74.7815985673],
                     [0.03440835545, 0.32836098991, 73.297125859],
@@ -3247,7 +3246,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.317e-08, 6.27946729049, 493.0424021651],
                     [2.42e-08, 3.66436222896, 3.6233367224],
                 ],
-            vec![
+            &[
                     [0.0147989637, 3.67205705317, 74.7815985673],
                     [0.00071212085, 6.22601006675, 63.7358983034],
                     [0.00068626972, 6.13411265052, 149.5631971346],
@@ -3763,7 +3762,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.185e-08, 6.0799711998, 63.6240237188],
                     [2.228e-08, 1.4245214891, 6219.339951688],
                 ],
-            vec![
+            &[
                     [0.00022439904, 0.6995311876, 74.7815985673],
                     [4.727037e-05, 1.69901641488, 63.7358983034],
                     [1.681903e-05, 4.64833551727, 70.8494453042],
@@ -3957,7 +3956,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.236e-08, 6.10115874045, 140.001969579],
                     [3.053e-08, 5.35047433775, 681.5417840896],
                 ],
-            vec![
+            &[
                     [1.164382e-05, 4.73453291602, 74.7815985673],
                     [2.12367e-06, 3.34255734999, 63.7358983034],
                     [1.96408e-06, 2.98004616318, 70.8494453042],
@@ -4014,7 +4013,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.291e-08, 4.814246016, 536.8045120954],
                     [2.213e-08, 2.20360299816, 465.9550667912],
                 ],
-            vec![
+            &[
                     [5.2996e-07, 3.00838033088, 74.7815985673],
                     [9.887e-08, 1.91399083603, 56.6223513026],
                     [7.008e-08, 5.08677527404, 11.0457002639],
@@ -4028,5 +4027,4 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [3.093e-08, 3.14159265359, 0.0],
                 ]
             ]
-    ]
-}
+    ];
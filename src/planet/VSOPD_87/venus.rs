@@ -1,8 +1,7 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
+/// Terms of the VSOP87D series for L, B and R, each in powers of time
+pub static TERMS: [&[&[[f64; 3]]]; 3] = [
+        &[
+            &[
                     [3.17614666774, 0.0, 0.0],
                     [0.01353968419, 5.59313319619, 10213.285546211],
                     [0.00089891645, 5.30650048468, 20426.571092422],
@@ -371,7 +370,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4.6e-10, 4.41738494249, 34570.0663348526],
                     [3.7e-10, 4.69675087759, 44007.8292697396],
                 ],
-            vec![
+            &[
                     [10213.52943052898, 0.0, 0.0],
                     [0.00095707712, 2.46424448979, 10213.285546211],
                     [0.00014444977, 0.51624564679, 20426.571092422],
@@ -588,7 +587,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.5e-10, 3.38876180652, 7058.5984613154],
                     [3.1e-10, 6.16829805337, 10192.5101507186],
                 ],
-            vec![
+            &[
                     [0.00054127076, 0.0, 0.0],
                     [3.89146e-05, 0.34514360047, 10213.285546211],
                     [1.33788e-05, 2.02011286082, 20426.571092422],
@@ -660,7 +659,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.3e-10, 5.79700427846, 29580.4747084438],
                     [1.4e-10, 3.6920522501, 27511.4678735372],
                 ],
-            vec![
+            &[
                     [1.35742e-06, 4.80389020993, 10213.285546211],
                     [7.7846e-07, 3.66876371591, 20426.571092422],
                     [2.6023e-07, 0.0, 0.0],
@@ -671,14 +670,14 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [6e-11, 1.27791479726, 10404.7338123226],
                     [6e-11, 5.76447068962, 10239.5838660108],
                 ],
-            vec![
+            &[
                     [1.14016e-06, 3.14159265359, 0.0],
                     [3.209e-08, 5.20514170164, 20426.571092422],
                     [1.714e-08, 2.51099591706, 10213.285546211],
                     [5e-10, 0.71356059861, 30639.856638633],
                     [2.3e-10, 5.68127607034, 40853.142184844],
                 ],
-            vec![
+            &[
                     [8.74e-09, 3.14159265359, 0.0],
                     [1.17e-09, 0.54643013, 20426.571092422],
                     [1.18e-09, 1.90548541922, 10213.285546211],
@@ -686,8 +685,8 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2e-11, 1.89460223529, 30639.856638633],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.05923638472, 0.26702775813, 10213.285546211],
                     [0.00040107978, 1.14737178106, 20426.571092422],
                     [0.00032814918, 3.14159265359, 0.0],
@@ -899,7 +898,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [4e-10, 5.32101847424, 42430.4857272918],
                     [5.1e-10, 5.29186795569, 29587.5882554446],
                 ],
-            vec![
+            &[
                     [0.00513347602, 1.80364310797, 10213.285546211],
                     [4.3801e-05, 3.38615711591, 20426.571092422],
                     [1.96586e-06, 2.53001197486, 30639.856638633],
@@ -1034,7 +1033,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [2.2e-10, 1.92092469688, 17085.9586657222],
                     [1.9e-10, 5.04938942644, 6681.2248533996],
                 ],
-            vec![
+            &[
                     [0.00022377665, 3.38509143877, 10213.285546211],
                     [2.81739e-06, 0.0, 0.0],
                     [1.73164e-06, 5.25563766915, 20426.571092422],
@@ -1095,7 +1094,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1e-10, 0.26447399758, 3930.2096962196],
                     [1.1e-10, 1.46728576671, 20419.45754542119],
                 ],
-            vec![
+            &[
                     [6.46671e-06, 4.99166565277, 10213.285546211],
                     [1.9952e-07, 3.14159265359, 0.0],
                     [5.54e-08, 0.77376923951, 20426.571092422],
@@ -1112,22 +1111,22 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [7e-11, 4.69592781899, 18073.7049386502],
                     [6e-11, 2.97926526705, 22003.9146348698],
                 ],
-            vec![
+            &[
                     [1.4102e-07, 0.31537190181, 10213.285546211],
                     [1.9e-09, 2.35466404492, 20426.571092422],
                     [1.64e-09, 0.74476215141, 30639.856638633],
                     [2.14e-09, 3.14159265359, 0.0],
                     [4e-11, 2.34190883009, 40853.142184844],
                 ],
-            vec![
+            &[
                     [2.39e-09, 2.05201727566, 10213.285546211],
                     [3.9e-10, 0.0, 0.0],
                     [1.1e-10, 3.82500275251, 20426.571092422],
                     [9e-11, 2.32953116868, 30639.856638633],
                 ]
             ],
-        vec![
-            vec![
+        &[
+            &[
                     [0.72334820905, 0.0, 0.0],
                     [0.00489824185, 4.02151832268, 10213.285546211],
                     [1.658058e-05, 4.90206728012, 20426.571092422],
@@ -1459,7 +1458,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [3e-10, 3.34585843979, 39793.7602546548],
                     [2.6e-10, 5.36096904409, 1478.8665740644],
                 ],
-            vec![
+            &[
                     [0.00034551039, 0.89198710598, 10213.285546211],
                     [2.34203e-06, 1.77224942714, 20426.571092422],
                     [2.33998e-06, 3.14159265359, 0.0],
@@ -1641,7 +1640,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [1.5e-10, 5.3451771514, 10198.033075026],
                     [1.3e-10, 0.45004137509, 20452.8694122218],
                 ],
-            vec![
+            &[
                     [1.406587e-05, 5.0636639519, 10213.285546211],
                     [1.5529e-07, 5.47321687981, 20426.571092422],
                     [1.3059e-07, 0.0, 0.0],
@@ -1706,7 +1705,7 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [8e-11, 4.67523115598, 25934.1243310894],
                     [9e-11, 5.97856553283, 9683.5945811164],
                 ],
-            vec![
+            &[
                     [4.9582e-07, 3.2226355452, 10213.285546211],
                     [8.31e-09, 3.21219077104, 20426.571092422],
                     [1.12e-09, 3.14159265359, 0.0],
@@ -1715,15 +1714,14 @@ pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
                     [6e-11, 0.20714935358, 10186.9872264112],
                     [5e-11, 0.68781956122, 8635.9420037632],
                 ],
-            vec![
+            &[
                     [5.73e-09, 0.9222969782, 10213.285546211],
                     [4e-10, 0.95468912157, 20426.571092422],
                     [6e-11, 3.14159265359, 0.0],
                 ],
-            vec![
+            &[
                     [4.5e-10, 0.30032866722, 10213.285546211],
                     [2e-11, 5.29627718483, 20426.571092422],
                 ]
             ]
-    ]
-}
+    ];
@@ -0,0 +1,140 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Times of perihelion and aphelion of the planets
+
+use planet;
+use search;
+
+/// Represents an apsis of a planet's orbit
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Apsis {
+    /// Least distance from the Sun
+    Perihelion,
+    /// Greatest distance from the Sun
+    Aphelion
+}
+
+/**
+Computes an approximate time of passage of a planet through perihelion
+or aphelion
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the passage nearest to the given
+        time
+
+The times are those of the planet's mean orbit (Meeus, chapter 38), and
+may be off by hours for Mercury, Venus and Mars, and by days, months or
+even years for the other planets, whose orbits are disturbed by one
+another. For the Earth, the time is that of the Earth-Moon barycenter.
+[passage()](./fn.passage.html) refines it.
+
+# Arguments
+
+* `planet`: Any variant of [Planet](../enum.Planet.html)
+* `apsis` : The [Apsis](./enum.Apsis.html)
+* `JD`    : Julian (Ephemeris) day near which to find the passage
+**/
+pub fn approx_passage(planet: &planet::Planet, apsis: &Apsis, JD: f64) -> f64 {
+
+    let (JD0, P, c, k_rate, year0) = match *planet {
+        planet::Planet::Mercury => (2451590.257,   87.96934963,  0.0,          4.15201, 2000.12),
+        planet::Planet::Venus   => (2451738.233,  224.7008188,  -0.0000000327, 1.62549, 2000.53),
+        planet::Planet::Earth   => (2451547.507,  365.2596358,   0.0000000156, 0.99997, 2000.01),
+        planet::Planet::Mars    => (2452195.026,  686.9957857,  -0.0000001187, 0.53166, 2001.78),
+        planet::Planet::Jupiter => (2455636.936, 4332.897065,    0.0001367,    0.08430, 2011.20),
+        planet::Planet::Saturn  => (2452830.12, 10764.21676,     0.000827,     0.03393, 2003.52),
+        planet::Planet::Uranus  => (2470213.5,  30694.8767,     -0.00541,      0.01190, 2051.1),
+        planet::Planet::Neptune => (2468895.1,  60190.33,        0.03429,      0.00607, 2047.5),
+    };
+
+    let year = 2000.0 + (JD - 2451545.0)/365.25;
+    let k = k_rate * (year - year0);
+    let k = match *apsis {
+        Apsis::Perihelion => k.round(),
+        Apsis::Aphelion   => (k - 0.5).round() + 0.5,
+    };
+
+    JD0 + k*(P + c*k)
+
+}
+
+/**
+Computes the time of passage of a planet through perihelion or aphelion
+
+# Returns
+
+`(JD, rad_vec)`
+
+* `JD`     : Julian (Ephemeris) day of the passage nearest to the
+             given time
+* `rad_vec`: Heliocentric radius vector of the planet at the time
+             *| in AU*
+
+The time from [approx_passage()](./fn.approx_passage.html) is refined
+by finding the least (or greatest) VSOP87 radius vector within a tenth
+of the planet's period around it. The window is sampled at a hundred
+steps, of up to 60 days for Neptune, before the least sample is
+refined. The times agree to within a day with the published passages
+of Jupiter, Saturn, Uranus and Neptune, and with a search of the
+radius vector at one day steps. For Uranus and Neptune the passage is
+itself ill-defined by a day or two, over which the radius vector stays
+within 1e-7 AU of its extreme value.
+
+# Arguments
+
+* `planet`: Any variant of [Planet](../enum.Planet.html)
+* `apsis` : The [Apsis](./enum.Apsis.html)
+* `JD`    : Julian (Ephemeris) day near which to find the passage
+**/
+pub fn passage(planet: &planet::Planet, apsis: &Apsis, JD: f64) -> (f64, f64) {
+
+    let JD_approx = approx_passage(planet, apsis, JD);
+
+    let sign = match *apsis {
+        Apsis::Perihelion => 1.0,
+        Apsis::Aphelion   => -1.0,
+    };
+    let f = |JD: f64| sign * planet::heliocent_coords(planet, JD).2;
+
+    let half_window = 0.05 * period(planet);
+    let JD = search::scan_min(f, JD_approx - half_window, JD_approx + half_window, 100);
+
+    (JD, sign * f(JD))
+
+}
+
+fn period(planet: &planet::Planet) -> f64 {
+
+    match *planet {
+        planet::Planet::Mercury => 87.969,
+        planet::Planet::Venus   => 224.701,
+        planet::Planet::Earth   => 365.260,
+        planet::Planet::Mars    => 686.996,
+        planet::Planet::Jupiter => 4332.897,
+        planet::Planet::Saturn  => 10764.217,
+        planet::Planet::Uranus  => 30694.877,
+        planet::Planet::Neptune => 60190.33,
+    }
+
+}
@@ -24,6 +24,7 @@ THE SOFTWARE.
 
 use coords;
 use angle;
+use angle::Angle;
 use time;
 
 /**
@@ -140,7 +141,9 @@ both of an observer on the Earth's surface.
 * `geograph_lat`: Observer's geographic latitude *| in radians*
 * `height`      : Observer's height above sea level *(meters)*
 **/
-pub fn rho_sin_cos_phi(geograph_lat: f64, height: f64) -> (f64, f64) {
+pub fn rho_sin_cos_phi<A: Angle>(geograph_lat: A, height: f64) -> (f64, f64) {
+
+    let geograph_lat = geograph_lat.rad();
 
     let u = (geograph_lat.tan() * pol_rad()/eq_rad()).atan();
     let x = height / (eq_rad() * 1000.0);
@@ -166,7 +169,9 @@ ellipsoid
 * `geograph_lat`: Geographic latitude of a point on the
                   ellipsoid *| in radians*
 **/
-pub fn rho(geograph_lat: f64) -> f64 {
+pub fn rho<A: Angle>(geograph_lat: A) -> f64 {
+
+    let geograph_lat = geograph_lat.rad();
 
     0.9983271
   + 0.0016764 * (2.0 * geograph_lat).cos()
@@ -194,7 +199,9 @@ Computes the radius of the parallel of a latitude
 * `geograph_lat`: Geographic latitude of a point
                   on the ellipsoid *| in radians*
 **/
-pub fn rad_of_parll_lat(geograph_lat: f64) -> f64 {
+pub fn rad_of_parll_lat<A: Angle>(geograph_lat: A) -> f64 {
+
+    let geograph_lat = geograph_lat.rad();
 
     let e = ecc_of_meridian();
 
@@ -217,7 +224,9 @@ Computes the linear velocity of a point at a latitude
                   the ellipsoid *| in radians*
 **/
 #[inline(always)]
-pub fn linear_velocity_at_lat(geograph_lat: f64) -> f64 {
+pub fn linear_velocity_at_lat<A: Angle>(geograph_lat: A) -> f64 {
+
+    let geograph_lat = geograph_lat.rad();
 
     rot_angular_velocity() * rad_of_parll_lat(geograph_lat)
 
@@ -237,7 +246,9 @@ at a latitude
 * `geograph_lat`: Geographic latitude of a point on the
                   ellipsoid *| in radians*
 **/
-pub fn rad_curv_of_meridian(lat: f64) -> f64 {
+pub fn rad_curv_of_meridian<A: Angle>(lat: A) -> f64 {
+
+    let lat = lat.rad();
 
     let e = ecc_of_meridian();
 
@@ -259,7 +270,9 @@ geocentric latitude
 
 * `geograph_lat`: Geographic latitude *| in radians*
 **/
-pub fn geograph_geocent_lat_diff(geograph_lat: f64) -> f64 {
+pub fn geograph_geocent_lat_diff<A: Angle>(geograph_lat: A) -> f64 {
+
+    let geograph_lat = geograph_lat.rad();
 
     angle::deg_frm_dms(0, 0, 692.73) * (2.0 * geograph_lat).sin()
   - angle::deg_frm_dms(0, 0, 1.16) * (4.0 * geograph_lat).sin()
@@ -276,15 +289,18 @@ Computes the equation of time *| in radians*
 * `nut_log` : Nutation correction for longitude *| in radians*
 * `tru_oblq`: True obliquity of the ecliptic *| in radians*
 **/
-pub fn equation_of_time (
+pub fn equation_of_time<A: Angle, B: Angle, C: Angle> (
 
     JD       : f64,
-    sun_asc  : f64,
-    nut_long : f64,
-    tru_oblq : f64
+    sun_asc  : A,
+    nut_long : B,
+    tru_oblq : C
 
 ) -> f64 {
 
+    let (sun_asc, nut_long, tru_oblq) =
+        (sun_asc.rad(), nut_long.rad(), tru_oblq.rad());
+
     let t = time::julian_mill(JD);
     let L = angle::limit_to_360(
         280.4664567 +
@@ -316,7 +332,9 @@ Computes the angle between diurnal path and the horizon
 * `observer_lat`: Observer's geographic latitude
                   *| in radians*
 **/
-pub fn angl_betwn_diurnal_path_and_hz(dec: f64, observer_lat: f64) -> f64 {
+pub fn angl_betwn_diurnal_path_and_hz<A: Angle, B: Angle>(dec: A, observer_lat: B) -> f64 {
+
+    let (dec, observer_lat) = (dec.rad(), observer_lat.rad());
 
     let B = dec.tan() * observer_lat.tan();
     let C = (1.0 - B*B).sqrt();
@@ -25,9 +25,12 @@ THE SOFTWARE.
 pub mod moon;
 
 use angle;
+use angle::Angle;
 use nutation;
 use planet;
 use coords;
+use ecliptic;
+use search;
 
 /**
 Computes Jupiter's equatorial semidiameter
@@ -80,6 +83,8 @@ pub struct Ephemeris {
     pub w1 : f64,
     /// Longitude of the central meridian for Rotational System II
     pub w2 : f64,
+    /// Longitude of the central meridian for Rotational System III
+    pub w3 : f64,
 }
 
 /**
@@ -97,15 +102,18 @@ of Jupiter
 * `nut_in_long`: Nutation in ecliptic longitude on `JD` *| in radians*
 * `nut_in_oblq`: Nutation in obliquity of the ecliptic on `JD` *| in radians*
 **/
-pub fn ephemeris (
+pub fn ephemeris<A: Angle, B: Angle, C: Angle> (
 
     JD          : f64,
-    mn_oblq     : f64,
-    nut_in_long : f64,
-    nut_in_oblq : f64
+    mn_oblq     : A,
+    nut_in_long : B,
+    nut_in_oblq : C
 
 ) -> Ephemeris {
 
+    let (mn_oblq, nut_in_long, nut_in_oblq) =
+        (mn_oblq.rad(), nut_in_long.rad(), nut_in_oblq.rad());
+
     let d = JD - 2433282.5;
     let T1 = d / 36525.0;
 
@@ -114,6 +122,7 @@ pub fn ephemeris (
 
     let W1 = angle::limit_to_360(17.710 + 877.90003539*d).to_radians();
     let W2 = angle::limit_to_360(16.838 + 870.27003539*d).to_radians();
+    let W3 = angle::limit_to_360(80.604 + 870.53603539*d).to_radians();
 
     let (l0, b0, R) = planet::heliocent_coords(&planet::Planet::Earth, JD);
 
@@ -161,6 +170,7 @@ pub fn ephemeris (
 
     let mut w1 = angle::limit_to_360(W1.to_degrees() - zeta.to_degrees() - 5.07033*jup_earth_dist);
     let mut w2 = angle::limit_to_360(W2.to_degrees() - zeta.to_degrees() - 5.02626*jup_earth_dist);
+    let mut w3 = angle::limit_to_360(W3.to_degrees() - zeta.to_degrees() - 5.02780*jup_earth_dist);
 
     let mut C =
         57.2958 * (2.0*r*jup_earth_dist + R*R - r*r - jup_earth_dist*jup_earth_dist)
@@ -170,6 +180,7 @@ pub fn ephemeris (
     }
     w1 = (w1 + C).to_radians();
     w2 = (w2 + C).to_radians();
+    w3 = (w3 + C).to_radians();
 
     let tru_oblq = mn_oblq + nut_in_oblq;
 
@@ -205,7 +216,84 @@ pub fn ephemeris (
         Ds: D_s,
         P : P,
         w1: w1,
-        w2: w2
+        w2: w2,
+        w3
     }
 
 }
+
+/// Represents a system of longitudes on Jupiter
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RotSystem {
+    /// System I, for the equatorial region
+    I,
+    /// System II, for the rest of the visible surface
+    II,
+    /// System III, for the rotation of the magnetic field, as measured
+    /// by radio
+    III
+}
+
+/**
+Computes the times at which a longitude on Jupiter crosses the central
+meridian
+
+# Returns
+
+* `transits`: Julian (Ephemeris) days at which the longitude crosses
+              the central meridian, between `JD_start` and `JD_end`,
+              sorted by time
+
+The longitude of the central meridian is that of
+[ephemeris()](./fn.ephemeris.html), and so includes the effects of
+light-time and phase.
+
+# Arguments
+
+* `rot_system`: The [RotSystem](./enum.RotSystem.html) of the longitude
+* `long`      : Longitude of the feature, such as the Great Red Spot
+                in System II *| in radians*
+* `JD_start`  : Julian (Ephemeris) day at which to start searching
+* `JD_end`    : Julian (Ephemeris) day at which to stop searching
+**/
+pub fn central_meridian_transits<A: Angle>(rot_system: &RotSystem, long: A, JD_start: f64, JD_end: f64) -> Vec<f64> {
+
+    let long = long.rad();
+
+    let diff_in_long = |JD: f64| {
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        let eph = ephemeris(JD, ecliptic::mn_oblq_laskar(JD), nut_in_long, nut_in_oblq);
+        let w = match *rot_system {
+            RotSystem::I   => eph.w1,
+            RotSystem::II  => eph.w2,
+            RotSystem::III => eph.w3,
+        };
+        w - long
+    };
+    let f = |JD: f64| diff_in_long(JD).sin();
+
+    let step = 0.05;
+    let n = ((JD_end - JD_start).max(0.0) / step).ceil() as usize;
+
+    let mut transits = Vec::new();
+    let mut JD1 = JD_start;
+    let mut f1 = f(JD1);
+    for i in 1..(n + 1) {
+        let JD2 = (JD_start + (i as f64)*step).min(JD_end);
+        let f2 = f(JD2);
+
+        if f1 < 0.0 && f2 >= 0.0 {
+            if let Some(JD) = search::bisect(&f, JD1, JD2) {
+                if diff_in_long(JD).cos() > 0.0 {
+                    transits.push(JD);
+                }
+            }
+        }
+
+        JD1 = JD2;
+        f1 = f2;
+    }
+
+    transits
+
+}
@@ -22,6 +22,11 @@ THE SOFTWARE.
 
 //! The four Galilean moons
 
+use planet;
+use search;
+use std;
+use time;
+
 /*
 
 Meeus referrs to the moons as Satellites I, II, III and IV.
@@ -31,6 +36,7 @@ and IV is Callisto. That mapping is used.
 */
 
 /// Represents a Galilean moon
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Moon {
     /// Io
     Io,
@@ -130,3 +136,637 @@ pub fn apprnt_rect_coords(JD: f64, moon: &Moon) -> (f64, f64) {
     (X, Y)
 
 }
+
+/**
+Computes the apparent rectangular coordinates of a Galilean moon,
+including its distance in front of or behind Jupiter
+
+# Returns
+
+`(X, Y, Z)`
+
+`X` and `Y` are measured as in
+[apprnt_rect_coords()](./fn.apprnt_rect_coords.html), and corrected
+for perspective: a moon in front of Jupiter is nearer to the observer
+than Jupiter's center, and so appears farther from it.
+
+`Z` is measured positively towards the Earth, in units of Jupiter's
+equatorial radius. The moon is in front of Jupiter when `Z` is
+positive, and behind it when `Z` is negative.
+
+This function implements the high accuracy method described in Meeus's
+book (chapter 44), which is based on Lieske's E5 theory. Light-time,
+including its difference across Jupiter's system, is corrected for.
+
+# Arguments
+
+* `JD`  : Julian (Ephemeris) day
+* `moon`: The [Moon](./enum.Moon.html)
+**/
+pub fn apprnt_rect_coords_XYZ(JD: f64, moon: &Moon) -> (f64, f64, f64) {
+
+    persp_rect_coords(JD, moon)[0]
+
+}
+
+/**
+Computes the radius of a Galilean moon
+
+# Returns
+
+* `radius`: Mean radius of the moon *| in units of Jupiter's
+            equatorial radius*
+
+# Arguments
+
+* `moon`: The [Moon](./enum.Moon.html)
+**/
+pub fn radius(moon: &Moon) -> f64 {
+
+    let r = match *moon {
+        Moon::Io       => 1821.6,
+        Moon::Europa   => 1560.8,
+        Moon::Ganymede => 2634.1,
+        Moon::Callisto => 2410.3,
+    };
+
+    r / JUPITER_EQ_RADIUS
+
+}
+
+/**
+Computes the geometry of a mutual event of two Galilean moons
+
+# Returns
+
+`(occult_sepr, eclipse_sepr)`
+
+* `occult_sepr` : Apparent separation of the centers of the two moons
+                  as seen from the Earth *| in units of Jupiter's
+                  equatorial radius*
+* `eclipse_sepr`: Apparent separation of the centers of the two moons
+                  as seen from the Sun *| in units of Jupiter's
+                  equatorial radius*
+
+`moon1` occults `moon2` when `occult_sepr` is less than the sum of
+their [radii](./fn.radius.html) and `moon1` is in front, that is, has
+the greater `Z` in
+[apprnt_rect_coords_XYZ()](./fn.apprnt_rect_coords_XYZ.html). In the
+same way, `moon1` eclipses `moon2` when `eclipse_sepr` is less than the
+sum of their radii and `moon1` is nearer the Sun. The separations are
+both scaled to the distance of Jupiter's center.
+
+# Arguments
+
+* `JD`   : Julian (Ephemeris) day
+* `moon1`: The first [Moon](./enum.Moon.html)
+* `moon2`: The second [Moon](./enum.Moon.html)
+**/
+pub fn mutual_sepr(JD: f64, moon1: &Moon, moon2: &Moon) -> (f64, f64) {
+
+    let p1 = persp_rect_coords(JD, moon1);
+    let p2 = persp_rect_coords(JD, moon2);
+
+    let sepr = |a: (f64, f64, f64), b: (f64, f64, f64)| {
+        ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+    };
+
+    (sepr(p1[0], p2[0]), sepr(p1[1], p2[1]))
+
+}
+
+/// Jupiter's equatorial radius, in kilometers
+const JUPITER_EQ_RADIUS: f64 = 71492.0;
+
+/// Applies the correction for perspective to the coordinates of
+/// rect_coords_E5()
+fn persp_rect_coords(JD: f64, moon: &Moon) -> [(f64, f64, f64); 2] {
+
+    let AU = 149597870.7 / JUPITER_EQ_RADIUS;
+
+    let persp = |(X, Y, Z, dist): (f64, f64, f64, f64)| {
+        let k = dist*AU / (dist*AU - Z);
+        (X*k, Y*k, Z)
+    };
+
+    let coords = rect_coords_E5(JD, moon);
+
+    [persp(coords[0]), persp(coords[1])]
+
+}
+
+/// Computes the rectangular coordinates of a moon with the high
+/// accuracy theory, as seen from the Earth and from the Sun, with the
+/// `Z` coordinate positive towards the observer, and the observer's
+/// distance from Jupiter in AU
+fn rect_coords_E5(JD: f64, moon: &Moon) -> [(f64, f64, f64, f64); 2] {
+
+    let (L0, B0, R0) = planet::heliocent_coords(&planet::Planet::Earth, JD);
+
+    let (mut l, mut b, mut r) = (0.0, 0.0, 0.0);
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    let mut light_time = 0.0;
+    for _ in 0..2 {
+        let (new_l, new_b, new_r) = planet::heliocent_coords(&planet::Planet::Jupiter, JD - light_time);
+        l = new_l; b = new_b; r = new_r;
+
+        let (new_x, new_y, new_z) = planet::geocent_ecl_rect_coords(L0, B0, R0, l, b, r);
+        x = new_x; y = new_y; z = new_z;
+
+        light_time = planet::light_time(planet::dist_frm_ecl_rect_coords(x, y, z));
+    }
+    let delta = planet::dist_frm_ecl_rect_coords(x, y, z);
+    let (lambda0, beta0) = planet::ecl_coords_frm_ecl_rect_coords(x, y, z);
+
+    let (X, Y, Z, R, psi) = jovicent_coords(JD - 2443000.5 - light_time, moon);
+
+    // precession from B1950.0 to the equinox of date
+    let T0 = (JD - 2433282.423) / 36525.0;
+    let P = (1.3966626*T0 + 0.0003088*T0*T0).to_radians();
+
+    // Jupiter's equator on its orbit, and Jupiter's orbit on the ecliptic
+    let T = time::julian_cent(JD);
+    let I = (3.120262 + 0.0006*(JD - 2415020.5)/36525.0).to_radians();
+    let Omega = (100.464407 + T*(1.0209774 + T*(0.00040315 + T*0.000000404))).to_radians();
+    let i = (1.303267 - T*(0.0054965 - T*(0.00000466 - T*0.000000002))).to_radians();
+    let Phi = psi + P - Omega;
+
+    let ecl_rect_coords = |X: f64, Y: f64, Z: f64| {
+        let (A1, B1, C1) = (X, Y*I.cos() - Z*I.sin(), Y*I.sin() + Z*I.cos());
+        let (A2, B2, C2) = (A1*Phi.cos() - B1*Phi.sin(), A1*Phi.sin() + B1*Phi.cos(), C1);
+        let (A3, B3, C3) = (A2, B2*i.cos() - C2*i.sin(), B2*i.sin() + C2*i.cos());
+        (A3*Omega.cos() - B3*Omega.sin(), A3*Omega.sin() + B3*Omega.cos(), C3)
+    };
+    let (A, B, C) = ecl_rect_coords(X, Y, Z);
+    let (A_pole, B_pole, C_pole) = ecl_rect_coords(0.0, 0.0, 1.0);
+
+    let K = match *moon {
+        Moon::Io       => 17295.0,
+        Moon::Europa   => 21819.0,
+        Moon::Ganymede => 27558.0,
+        Moon::Callisto => 36548.0,
+    };
+
+    // turns the moon to the direction of an observer, with the y-axis
+    // along the projection of Jupiter's axis of rotation
+    let view = |lambda0: f64, beta0: f64, dist: f64| {
+        let rotate = |A4: f64, B4: f64, C4: f64| {
+            let A5 = A4*lambda0.sin() - B4*lambda0.cos();
+            let B5 = A4*lambda0.cos() + B4*lambda0.sin();
+            (A5, C4*beta0.sin() + B5*beta0.cos(), C4*beta0.cos() - B5*beta0.sin())
+        };
+        let (A6, B6, C6) = rotate(A, B, C);
+        let (A6_pole, _, C6_pole) = rotate(A_pole, B_pole, C_pole);
+        let D = A6_pole.atan2(C6_pole);
+
+        let mut X = A6*D.cos() - C6*D.sin();
+        let Y = A6*D.sin() + C6*D.cos();
+        let Z = -B6;
+
+        // correct for differential light-time
+        X += Z.abs()*(1.0 - (X/R).powi(2)).sqrt()/K;
+
+        (X, Y, Z, dist)
+    };
+
+    [view(lambda0, beta0, delta), view(l, b, r)]
+
+}
+
+/// Computes the rectangular coordinates of a moon referred to
+/// Jupiter's equator, with the x-axis towards the ascending node
+/// of the equator on the ecliptic, and that node's longitude
+/// referred to the equinox of B1950.0
+fn jovicent_coords(t: f64, moon: &Moon) -> (f64, f64, f64, f64, f64) {
+
+    let sin = |x: f64| x.to_radians().sin();
+    let cos = |x: f64| x.to_radians().cos();
+
+    // mean longitudes
+    let l1 = 106.07719 + 203.488955790*t;
+    let l2 = 175.73161 + 101.374724735*t;
+    let l3 = 120.55883 + 50.317609207*t;
+    let l4 = 84.44459  + 21.571071177*t;
+
+    // longitudes of the perijoves
+    let p1 = 97.0881  + 0.16138586*t;
+    let p2 = 154.8663 + 0.04726307*t;
+    let p3 = 188.1840 + 0.00712734*t;
+    let p4 = 335.2868 + 0.00184000*t;
+
+    // longitudes of the nodes on Jupiter's equator
+    let w1 = 312.3346 - 0.13279386*t;
+    let w2 = 100.4411 - 0.03263064*t;
+    let w3 = 119.1942 - 0.00717703*t;
+    let w4 = 322.6186 - 0.00175934*t;
+
+    // principal inequality in the longitude of Jupiter
+    let Gamma = 0.33033*sin(163.679 + 0.0010512*t) + 0.03439*sin(34.486 - 0.0161731*t);
+
+    // phase of the free libration
+    let Phi = 199.6766 + 0.17379190*t;
+
+    // longitude of the node of the equator of Jupiter on the ecliptic
+    let psi = 316.5182 - 0.00000208*t;
+
+    // mean anomalies of Jupiter and Saturn
+    let G = 30.23756 + 0.0830925701*t + Gamma;
+    let G1 = 31.97853 + 0.0334597339*t;
+
+    // longitude of the perihelion of Jupiter
+    let Pi = 13.469942;
+
+    let (L, B, R) = match *moon {
+        Moon::Io => {
+            let sigma =
+                0.47259*sin(2.0*(l1 - l2))
+                - 0.03478*sin(p3 - p4)
+                + 0.01081*sin(l2 - 2.0*l3 + p3)
+                + 0.00738*sin(Phi)
+                + 0.00713*sin(l2 - 2.0*l3 + p2)
+                - 0.00674*sin(p1 + p3 - 2.0*Pi - 2.0*G)
+                + 0.00666*sin(l2 - 2.0*l3 + p4)
+                + 0.00445*sin(l1 - p3)
+                - 0.00354*sin(l1 - l2)
+                - 0.00317*sin(2.0*psi - 2.0*Pi)
+                + 0.00265*sin(l1 - p4)
+                - 0.00186*sin(G)
+                + 0.00162*sin(p2 - p3)
+                + 0.00158*sin(4.0*(l1 - l2))
+                - 0.00155*sin(l1 - l3)
+                - 0.00138*sin(psi + w3 - 2.0*Pi - 2.0*G)
+                - 0.00115*sin(2.0*(l1 - 2.0*l2 + w2))
+                + 0.00089*sin(p2 - p4)
+                + 0.00085*sin(l1 + p3 - 2.0*Pi - 2.0*G)
+                + 0.00083*sin(w2 - w3)
+                + 0.00053*sin(psi - w2);
+            let L = l1 + sigma;
+            let B = (
+                0.0006393*sin(L - w1)
+                + 0.0001825*sin(L - w2)
+                + 0.0000329*sin(L - w3)
+                - 0.0000311*sin(L - psi)
+                + 0.0000093*sin(L - w4)
+                + 0.0000075*sin(3.0*L - 4.0*l2 - 1.9927*sigma + w2)
+                + 0.0000046*sin(L + psi - 2.0*Pi - 2.0*G)
+            ).atan();
+            let R = 5.90569*(
+                1.0
+                - 0.0041339*cos(2.0*(l1 - l2))
+                - 0.0000387*cos(l1 - p3)
+                - 0.0000214*cos(l1 - p4)
+                + 0.0000170*cos(l1 - l2)
+                - 0.0000131*cos(4.0*(l1 - l2))
+                + 0.0000106*cos(l1 - l3)
+                - 0.0000066*cos(l1 + p3 - 2.0*Pi - 2.0*G)
+            );
+            (L, B, R)
+        },
+        Moon::Europa => {
+            let sigma =
+                1.06476*sin(2.0*(l2 - l3))
+                + 0.04256*sin(l1 - 2.0*l2 + p3)
+                + 0.03581*sin(l2 - p3)
+                + 0.02395*sin(l1 - 2.0*l2 + p4)
+                + 0.01984*sin(l2 - p4)
+                - 0.01778*sin(Phi)
+                + 0.01654*sin(l2 - p2)
+                + 0.01334*sin(l2 - 2.0*l3 + p2)
+                + 0.01294*sin(p3 - p4)
+                - 0.01142*sin(l2 - l3)
+                - 0.01057*sin(G)
+                - 0.00775*sin(2.0*(psi - Pi))
+                + 0.00524*sin(2.0*(l1 - l2))
+                - 0.00460*sin(l1 - l3)
+                + 0.00316*sin(psi - 2.0*G + w3 - 2.0*Pi)
+                - 0.00203*sin(p1 + p3 - 2.0*Pi - 2.0*G)
+                + 0.00146*sin(psi - w3)
+                - 0.00145*sin(2.0*G)
+                + 0.00125*sin(psi - w4)
+                - 0.00115*sin(l1 - 2.0*l3 + p3)
+                - 0.00094*sin(2.0*(l2 - w2))
+                + 0.00086*sin(2.0*(l1 - 2.0*l2 + w2))
+                - 0.00086*sin(5.0*G1 - 2.0*G + 52.225)
+                - 0.00078*sin(l2 - l4)
+                - 0.00064*sin(3.0*l3 - 7.0*l4 + 4.0*p4)
+                + 0.00064*sin(p1 - p4)
+                - 0.00063*sin(l1 - 2.0*l3 + p4)
+                + 0.00058*sin(w3 - w4)
+                + 0.00056*sin(2.0*(psi - Pi - G))
+                + 0.00056*sin(2.0*(l2 - l4))
+                + 0.00055*sin(2.0*(l1 - l3))
+                + 0.00052*sin(3.0*l3 - 7.0*l4 + p3 + 3.0*p4)
+                - 0.00043*sin(l1 - p3)
+                + 0.00041*sin(5.0*(l2 - l3))
+                + 0.00041*sin(p4 - Pi)
+                + 0.00032*sin(w2 - w3)
+                + 0.00032*sin(2.0*(l3 - G - Pi));
+            let L = l2 + sigma;
+            let B = (
+                0.0081004*sin(L - w2)
+                + 0.0004512*sin(L - w3)
+                - 0.0003284*sin(L - psi)
+                + 0.0001160*sin(L - w4)
+                + 0.0000272*sin(l1 - 2.0*l3 + 1.0146*sigma + w2)
+                - 0.0000144*sin(L - w1)
+                + 0.0000143*sin(L + psi - 2.0*Pi - 2.0*G)
+                + 0.0000035*sin(L - psi + G)
+                - 0.0000028*sin(l1 - 2.0*l3 + 1.0146*sigma + w3)
+            ).atan();
+            let R = 9.39657*(
+                1.0
+                + 0.0093848*cos(l1 - l2)
+                - 0.0003116*cos(l2 - p3)
+                - 0.0001744*cos(l2 - p4)
+                - 0.0001442*cos(l2 - p2)
+                + 0.0000553*cos(l2 - l3)
+                + 0.0000523*cos(l1 - l3)
+                - 0.0000290*cos(2.0*(l1 - l2))
+                + 0.0000164*cos(2.0*(l2 - w2))
+                + 0.0000107*cos(l1 - 2.0*l3 + p3)
+                - 0.0000102*cos(l2 - p1)
+                - 0.0000091*cos(2.0*(l1 - l3))
+            );
+            (L, B, R)
+        },
+        Moon::Ganymede => {
+            let sigma =
+                0.16490*sin(l3 - p3)
+                + 0.09081*sin(l3 - p4)
+                - 0.06907*sin(l2 - l3)
+                + 0.03784*sin(p3 - p4)
+                + 0.01846*sin(2.0*(l3 - l4))
+                - 0.01340*sin(G)
+                - 0.01014*sin(2.0*(psi - Pi))
+                + 0.00704*sin(l2 - 2.0*l3 + p3)
+                - 0.00620*sin(l2 - 2.0*l3 + p2)
+                - 0.00541*sin(l3 - l4)
+                + 0.00381*sin(l2 - 2.0*l3 + p4)
+                + 0.00235*sin(psi - w3)
+                + 0.00198*sin(psi - w4)
+                + 0.00176*sin(Phi)
+                + 0.00130*sin(3.0*(l3 - l4))
+                + 0.00125*sin(l1 - l3)
+                - 0.00119*sin(5.0*G1 - 2.0*G + 52.225)
+                + 0.00109*sin(l1 - l2)
+                - 0.00100*sin(3.0*l3 - 7.0*l4 + 4.0*p4)
+                + 0.00091*sin(w3 - w4)
+                + 0.00080*sin(3.0*l3 - 7.0*l4 + p3 + 3.0*p4)
+                - 0.00075*sin(2.0*l2 - 3.0*l3 + p3)
+                + 0.00072*sin(p1 + p3 - 2.0*Pi - 2.0*G)
+                + 0.00069*sin(p4 - Pi)
+                - 0.00058*sin(2.0*l3 - 3.0*l4 + p4)
+                - 0.00057*sin(l3 - 2.0*l4 + p4)
+                + 0.00056*sin(l3 + p3 - 2.0*Pi - 2.0*G)
+                - 0.00052*sin(l2 - 2.0*l3 + p1)
+                - 0.00050*sin(p2 - p3)
+                + 0.00048*sin(l3 - 2.0*l4 + p3)
+                - 0.00045*sin(2.0*l2 - 3.0*l3 + p4)
+                - 0.00041*sin(p2 - p4)
+                - 0.00038*sin(2.0*G)
+                - 0.00037*sin(p3 - p4 + w3 - w4)
+                - 0.00032*sin(3.0*l3 - 7.0*l4 + 2.0*p3 + 2.0*p4)
+                + 0.00030*sin(4.0*(l3 - l4))
+                + 0.00029*sin(l3 + p4 - 2.0*Pi - 2.0*G)
+                - 0.00028*sin(w3 + psi - 2.0*Pi - 2.0*G)
+                + 0.00026*sin(l3 - Pi - G)
+                + 0.00024*sin(l2 - 3.0*l3 + 2.0*l4)
+                + 0.00021*sin(2.0*(l3 - Pi - G))
+                - 0.00021*sin(l3 - p2)
+                + 0.00017*sin(2.0*(l3 - p3));
+            let L = l3 + sigma;
+            let B = (
+                0.0032402*sin(L - w3)
+                - 0.0016911*sin(L - psi)
+                + 0.0006847*sin(L - w4)
+                - 0.0002797*sin(L - w2)
+                + 0.0000321*sin(L + psi - 2.0*Pi - 2.0*G)
+                + 0.0000051*sin(L - psi + G)
+                - 0.0000045*sin(L - psi - G)
+                - 0.0000045*sin(L + psi - 2.0*Pi)
+                + 0.0000037*sin(L + psi - 2.0*Pi - 3.0*G)
+                + 0.0000030*sin(2.0*l2 - 3.0*L + 4.03*sigma + w2)
+                - 0.0000021*sin(2.0*l2 - 3.0*L + 4.03*sigma + w3)
+            ).atan();
+            let R = 14.98832*(
+                1.0
+                - 0.0014388*cos(l3 - p3)
+                - 0.0007919*cos(l3 - p4)
+                + 0.0006342*cos(l2 - l3)
+                - 0.0001761*cos(2.0*(l3 - l4))
+                + 0.0000294*cos(l3 - l4)
+                - 0.0000156*cos(3.0*(l3 - l4))
+                + 0.0000156*cos(l1 - l3)
+                - 0.0000153*cos(l1 - l2)
+                + 0.0000070*cos(2.0*l2 - 3.0*l3 + p3)
+                - 0.0000051*cos(l3 + p3 - 2.0*Pi - 2.0*G)
+            );
+            (L, B, R)
+        },
+        Moon::Callisto => {
+            let sigma =
+                0.84287*sin(l4 - p4)
+                + 0.03431*sin(p4 - p3)
+                - 0.03305*sin(2.0*(psi - Pi))
+                - 0.03211*sin(G)
+                - 0.01862*sin(l4 - p3)
+                + 0.01186*sin(psi - w4)
+                + 0.00623*sin(l4 + p4 - 2.0*G - 2.0*Pi)
+                + 0.00387*sin(2.0*(l4 - p4))
+                - 0.00284*sin(5.0*G1 - 2.0*G + 52.225)
+                - 0.00234*sin(2.0*(psi - p4))
+                - 0.00223*sin(l3 - l4)
+                - 0.00208*sin(l4 - Pi)
+                + 0.00178*sin(psi + w4 - 2.0*p4)
+                + 0.00134*sin(p4 - Pi)
+                + 0.00125*sin(2.0*(l4 - G - Pi))
+                - 0.00117*sin(2.0*G)
+                - 0.00112*sin(2.0*(l3 - l4))
+                + 0.00107*sin(3.0*l3 - 7.0*l4 + 4.0*p4)
+                + 0.00102*sin(l4 - G - Pi)
+                + 0.00096*sin(2.0*l4 - psi - w4)
+                + 0.00087*sin(2.0*(psi - w4))
+                - 0.00085*sin(3.0*l3 - 7.0*l4 + p3 + 3.0*p4)
+                + 0.00085*sin(l3 - 2.0*l4 + p4)
+                - 0.00081*sin(2.0*(l4 - psi))
+                + 0.00071*sin(l4 + p4 - 2.0*Pi - 3.0*G)
+                + 0.00061*sin(l1 - l4)
+                - 0.00056*sin(psi - w3)
+                - 0.00054*sin(l3 - 2.0*l4 + p3)
+                + 0.00051*sin(l2 - l4)
+                + 0.00042*sin(2.0*(psi - G - Pi))
+                + 0.00039*sin(2.0*(p4 - w4))
+                + 0.00036*sin(psi + Pi - p4 - w4)
+                + 0.00035*sin(2.0*G1 - G + 188.37)
+                - 0.00035*sin(l4 - p4 + 2.0*Pi - 2.0*psi)
+                - 0.00032*sin(l4 + p4 - 2.0*Pi - G)
+                + 0.00030*sin(2.0*G1 - 2.0*G + 149.15)
+                + 0.00029*sin(3.0*l3 - 7.0*l4 + 2.0*p3 + 2.0*p4)
+                + 0.00028*sin(l4 - p4 + 2.0*psi - 2.0*Pi)
+                - 0.00028*sin(2.0*(l4 - w4))
+                - 0.00027*sin(p3 - p4 + w3 - w4)
+                - 0.00026*sin(5.0*G1 - 3.0*G + 188.37)
+                + 0.00025*sin(w4 - w3)
+                - 0.00025*sin(l2 - 3.0*l3 + 2.0*l4)
+                - 0.00023*sin(3.0*(l3 - l4))
+                + 0.00021*sin(2.0*l4 - 2.0*Pi - 3.0*G)
+                - 0.00021*sin(2.0*l3 - 3.0*l4 + p4)
+                + 0.00019*sin(l4 - p4 - G)
+                - 0.00019*sin(2.0*l4 - p3 - p4)
+                - 0.00018*sin(l4 - p4 + G)
+                - 0.00016*sin(l4 + p3 - 2.0*Pi - 2.0*G);
+            let L = l4 + sigma;
+            let B = (
+                - 0.0076579*sin(L - psi)
+                + 0.0044134*sin(L - w4)
+                - 0.0005112*sin(L - w3)
+                + 0.0000773*sin(L + psi - 2.0*Pi - 2.0*G)
+                + 0.0000104*sin(L - psi + G)
+                - 0.0000102*sin(L - psi - G)
+                + 0.0000088*sin(L + psi - 2.0*Pi - 3.0*G)
+                - 0.0000038*sin(L + psi - 2.0*Pi - G)
+            ).atan();
+            let R = 26.36273*(
+                1.0
+                - 0.0073546*cos(l4 - p4)
+                + 0.0001621*cos(l4 - p3)
+                + 0.0000974*cos(l3 - l4)
+                - 0.0000543*cos(l4 + p4 - 2.0*Pi - 2.0*G)
+                - 0.0000271*cos(2.0*(l4 - p4))
+                + 0.0000182*cos(l4 - Pi)
+                + 0.0000177*cos(2.0*(l3 - l4))
+                - 0.0000167*cos(2.0*l4 - psi - w4)
+                + 0.0000167*cos(psi - w4)
+                - 0.0000155*cos(2.0*(l4 - Pi - G))
+                + 0.0000142*cos(2.0*(l4 - psi))
+                + 0.0000105*cos(l1 - l4)
+                + 0.0000092*cos(l2 - l4)
+                - 0.0000089*cos(l4 - Pi - G)
+                - 0.0000062*cos(l4 + p4 - 2.0*Pi - 3.0*G)
+                + 0.0000048*cos(2.0*(l4 - w4))
+            );
+            (L, B, R)
+        },
+    };
+    (
+        R*cos(L - psi)*B.cos(),
+        R*sin(L - psi)*B.cos(),
+        R*B.sin(),
+        R,
+        psi.to_radians()
+    )
+
+}
+
+/// Represents a kind of phenomenon of a Galilean moon
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PhenomenonType {
+    /// The moon begins to cross Jupiter's disk
+    TransitIngress,
+    /// The moon ends crossing Jupiter's disk
+    TransitEgress,
+    /// The moon's shadow begins to cross Jupiter's disk
+    ShadowIngress,
+    /// The moon's shadow ends crossing Jupiter's disk
+    ShadowEgress,
+    /// The moon disappears behind Jupiter's disk
+    OccultationDisappearance,
+    /// The moon reappears from behind Jupiter's disk
+    OccultationReappearance,
+    /// The moon enters Jupiter's shadow
+    EclipseDisappearance,
+    /// The moon leaves Jupiter's shadow
+    EclipseReappearance
+}
+
+/// Represents a phenomenon of a Galilean moon
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Phenomenon {
+    /// The moon
+    pub moon: Moon,
+    /// Type of the phenomenon
+    pub phenom_type: PhenomenonType,
+    /// Julian (Ephemeris) day of the phenomenon, as seen from the Earth
+    pub JD: f64
+}
+
+/**
+Finds the transits, shadow transits, occultations and eclipses of the
+Galilean moons in a time range
+
+# Returns
+
+* `phenomena`: Every phenomenon between `JD_start` and `JD_end`,
+               sorted by time
+
+The times are those of the center of the moon (or of its shadow)
+crossing the limb of Jupiter's disk (or of its shadow), from the
+positions of [apprnt_rect_coords_XYZ()](./fn.apprnt_rect_coords_XYZ.html)
+and the corresponding ones as seen from the Sun. They agree with the
+published predictions for the triple shadow transit of 2015 January 24
+to within a minute. Jupiter's shadow is taken to be a cylinder, and its
+disk an ellipse with the flattening of
+[pol_semidiameter()](../fn.pol_semidiameter.html). All phenomena are
+returned, including those that can't be seen because the moon is then
+also hidden behind Jupiter or in its shadow.
+
+The range is stepped through 0.005 day (7.2 minutes) at a time, so a
+grazing phenomenon, in which the moon or its shadow is over the edge of
+the disk for less than that, can be missed. Only Callisto, whose path
+can pass close to Jupiter's poles, has such grazing phenomena.
+
+# Arguments
+
+* `JD_start`: Julian (Ephemeris) day at which to start searching
+* `JD_end`  : Julian (Ephemeris) day at which to stop searching
+**/
+pub fn phenomena(JD_start: f64, JD_end: f64) -> Vec<Phenomenon> {
+
+    let k = super::eq_semidiameter(1.0) / super::pol_semidiameter(1.0);
+    let step = 0.005;
+    let n = ((JD_end - JD_start).max(0.0) / step).ceil() as usize;
+
+    let mut phenomena = Vec::new();
+
+    for moon in &[Moon::Io, Moon::Europa, Moon::Ganymede, Moon::Callisto] {
+        for view in 0..2 {
+            let disk = |JD: f64| {
+                let (X, Y, _) = persp_rect_coords(JD, moon)[view];
+                X*X + k*k*Y*Y - 1.0
+            };
+
+            let mut JD1 = JD_start;
+            let mut f1 = disk(JD1);
+            for i in 1..(n + 1) {
+                let JD2 = (JD_start + (i as f64)*step).min(JD_end);
+                let f2 = disk(JD2);
+
+                if f1 * f2 <= 0.0 && f1 != 0.0 {
+                    if let Some(JD) = search::bisect(&disk, JD1, JD2) {
+                        let entering = f2 < f1;
+                        let in_front = persp_rect_coords(JD, moon)[view].2 > 0.0;
+                        let phenom_type = match (view, in_front, entering) {
+                            (0, true,  true)  => PhenomenonType::TransitIngress,
+                            (0, true,  false) => PhenomenonType::TransitEgress,
+                            (0, false, true)  => PhenomenonType::OccultationDisappearance,
+                            (0, false, false) => PhenomenonType::OccultationReappearance,
+                            (_, true,  true)  => PhenomenonType::ShadowIngress,
+                            (_, true,  false) => PhenomenonType::ShadowEgress,
+                            (_, false, true)  => PhenomenonType::EclipseDisappearance,
+                            (_, false, false) => PhenomenonType::EclipseReappearance,
+                        };
+                        phenomena.push(Phenomenon { moon: *moon, phenom_type, JD });
+                    }
+                }
+
+                JD1 = JD2;
+                f1 = f2;
+            }
+        }
+    }
+
+    phenomena.sort_by(|a, b| a.JD.partial_cmp(&b.JD).unwrap_or(std::cmp::Ordering::Equal));
+
+    phenomena
+
+}
@@ -23,6 +23,8 @@ THE SOFTWARE.
 //! Mars
 
 use angle;
+use angle::Angle;
+use angle::Radians;
 use planet;
 use time;
 use coords;
@@ -126,16 +128,19 @@ Mars
 * `nut_in_oblq`          : Nutation in obliquity of the ecliptic on
                            `JD` *| in radians*
 **/
-pub fn ephemeris (
+pub fn ephemeris<A: Angle, B: Angle, C: Angle> (
 
     JD                    : f64,
     north_pole_ecl_coords : &coords::EclPoint,
-    mn_oblq               : f64,
-    nut_in_long           : f64,
-    nut_in_oblq           : f64
+    mn_oblq               : A,
+    nut_in_long           : B,
+    nut_in_oblq           : C
 
 ) -> Ephemeris {
 
+    let (mn_oblq, nut_in_long, nut_in_oblq) =
+        (mn_oblq.rad(), nut_in_long.rad(), nut_in_oblq.rad());
+
     let (mut lambda0, beta0) = (north_pole_ecl_coords.long, north_pole_ecl_coords.lat);
 
     let (l0, b0, R) = planet::heliocent_coords(&planet::Planet::Earth, JD);
@@ -183,8 +188,8 @@ pub fn ephemeris (
       + 350.89200025 * (JD - light_time - 2433282.5)
     ).to_radians();
 
-    let asc0 = coords::asc_frm_ecl(lambda0, beta0, mn_oblq);
-    let dec0 = coords::dec_frm_ecl(lambda0, beta0, mn_oblq);
+    let asc0 = coords::asc_frm_ecl(Radians(lambda0), Radians(beta0), Radians(mn_oblq));
+    let dec0 = coords::dec_frm_ecl(Radians(lambda0), Radians(beta0), Radians(mn_oblq));
 
     let u = y*mn_oblq.cos() - z*mn_oblq.sin();
     let v = y*mn_oblq.sin() + z*mn_oblq.cos();
@@ -203,10 +208,10 @@ pub fn ephemeris (
     lambda0 += nut_in_long;
     let true_oblq = mn_oblq + nut_in_oblq;
 
-    let asc01 = coords::asc_frm_ecl(lambda0, beta0, true_oblq);
-    let dec01 = coords::dec_frm_ecl(lambda0, beta0, true_oblq);
-    let asc1 = coords::asc_frm_ecl(lambda, beta, true_oblq);
-    let dec1 = coords::dec_frm_ecl(lambda, beta, true_oblq);
+    let asc01 = coords::asc_frm_ecl(Radians(lambda0), Radians(beta0), Radians(true_oblq));
+    let dec01 = coords::dec_frm_ecl(Radians(lambda0), Radians(beta0), Radians(true_oblq));
+    let asc1 = coords::asc_frm_ecl(Radians(lambda), Radians(beta), Radians(true_oblq));
+    let dec1 = coords::dec_frm_ecl(Radians(lambda), Radians(beta), Radians(true_oblq));
 
     let P = (dec01.cos() * (asc01 - asc1).sin()).atan2 (
         dec01.sin() * dec1.cos()
@@ -0,0 +1,230 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Oppositions, conjunctions, greatest elongations and stations of the planets
+
+use aberr;
+use angle;
+use angle::Radians;
+use coords;
+use error::Error;
+use nutation;
+use planet;
+use search;
+use std;
+use sun;
+
+/// Represents a kind of planetary phenomenon
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PhenomenonType {
+    /// Geocentric longitude of an outer planet differs from the Sun's by 180°
+    Opposition,
+    /// Geocentric longitude of an outer planet equals the Sun's
+    Conjunction,
+    /// Inner planet between the Earth and the Sun, at the Sun's longitude
+    InferiorConjunction,
+    /// Inner planet beyond the Sun, at the Sun's longitude
+    SuperiorConjunction,
+    /// Inner planet at its greatest angular distance east of the Sun
+    GreatestElongEast,
+    /// Inner planet at its greatest angular distance west of the Sun
+    GreatestElongWest,
+    /// Planet's geocentric longitude stops increasing and starts decreasing
+    StationRetrograde,
+    /// Planet's geocentric longitude stops decreasing and starts increasing
+    StationDirect
+}
+
+/// Represents a planetary phenomenon
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Phenomenon {
+    /// Type of the phenomenon
+    pub phenom_type: PhenomenonType,
+    /// Julian (Ephemeris) day of the phenomenon
+    pub JD: f64,
+    /// Elongation of the planet from the Sun at the time *| in radians*
+    pub elong: f64
+}
+
+/**
+Finds the oppositions, conjunctions, greatest elongations and stations
+of a planet in a time range
+
+# Returns
+
+* `phenomena`: Every phenomenon between `JD_start` and `JD_end`,
+               sorted by time
+
+The positions used are the apparent geocentric ones of the planet and
+the Sun, both converted to the FK5 system and corrected for nutation
+and aberration: the planet's from
+[geocent_apprnt_ecl_coords()](../fn.geocent_apprnt_ecl_coords.html),
+already corrected for light-time, and the Sun's from
+[sun::geocent_ecl_pos()](../../sun/fn.geocent_ecl_pos.html).
+
+The range is stepped through a day at a time, and each event found is
+then narrowed down to well under a second, so the error of the times is
+that of the positions. An error in the longitudes moves a conjunction
+or an opposition by that error divided by the relative motion of the
+planet and the Sun: about a second for every 0.1″ at an inferior
+conjunction or an opposition, and up to ten times as much at a superior
+conjunction of Venus. Greatest elongations and stations are extrema,
+which the same errors can move by several minutes.
+
+Oppositions are only found for the outer planets, and greatest
+elongations only for Mercury and Venus.
+
+# Arguments
+
+* `planet`  : Any variant of [Planet](../enum.Planet.html) except `Planet::Earth`
+* `JD_start`: Julian (Ephemeris) day at which to start searching
+* `JD_end`  : Julian (Ephemeris) day at which to stop searching
+**/
+pub fn phenomena(planet: &planet::Planet, JD_start: f64, JD_end: f64) -> Result<Vec<Phenomenon>, Error> {
+
+    if *planet == planet::Planet::Earth {
+        return Err(Error::InvalidPlanet {
+            planet: *planet,
+            function: "planet::phenomena::phenomena"
+        });
+    }
+    let inner = matches!(*planet, planet::Planet::Mercury | planet::Planet::Venus);
+
+    let dlong = |JD: f64| diff_in_long(planet, JD).sin();
+    let neg_elong = |JD: f64| -elong(planet, JD);
+    let long_rate = |JD: f64| {
+        let h = 0.01;
+        (planet_long(planet, JD + h) - planet_long(planet, JD - h)).sin()
+    };
+
+    let n = ((JD_end - JD_start).max(0.0)).ceil() as usize;
+    let times: Vec<f64> = (0..(n + 1))
+        .map(|i| (JD_start + i as f64).min(JD_end))
+        .collect();
+    let dlongs: Vec<f64> = times.iter().map(|&JD| dlong(JD)).collect();
+    let elongs: Vec<f64> = times.iter().map(|&JD| -neg_elong(JD)).collect();
+    let longs: Vec<f64> = times.iter().map(|&JD| planet_long(planet, JD)).collect();
+
+    let mut phenomena = Vec::new();
+    let mut push = |phenom_type, JD: f64| phenomena.push(Phenomenon {
+        phenom_type,
+        JD,
+        elong: elong(planet, JD)
+    });
+
+    for i in 0..n {
+        if dlongs[i] * dlongs[i + 1] <= 0.0 && dlongs[i] != 0.0 {
+            if let Some(JD) = search::bisect(&dlong, times[i], times[i + 1]) {
+                let phenom_type = if diff_in_long(planet, JD).cos() < 0.0 {
+                    PhenomenonType::Opposition
+                } else if !inner {
+                    PhenomenonType::Conjunction
+                } else if planet_point(planet, JD).1 < sun_point(JD).1 {
+                    PhenomenonType::InferiorConjunction
+                } else {
+                    PhenomenonType::SuperiorConjunction
+                };
+                push(phenom_type, JD);
+            }
+        }
+    }
+
+    for i in 1..n {
+        if inner && elongs[i] > elongs[i - 1] && elongs[i] >= elongs[i + 1] {
+            let JD = search::golden_section_min(neg_elong, times[i - 1], times[i + 1]);
+            let phenom_type = if diff_in_long(planet, JD).sin() > 0.0 {
+                PhenomenonType::GreatestElongEast
+            } else {
+                PhenomenonType::GreatestElongWest
+            };
+            push(phenom_type, JD);
+        }
+
+        let before = (longs[i] - longs[i - 1]).sin();
+        let after = (longs[i + 1] - longs[i]).sin();
+        if before * after <= 0.0 && before != 0.0 {
+            if let Some(JD) = search::bisect(&long_rate, times[i - 1], times[i + 1]) {
+                let phenom_type = if before > 0.0 {
+                    PhenomenonType::StationRetrograde
+                } else {
+                    PhenomenonType::StationDirect
+                };
+                push(phenom_type, JD);
+            }
+        }
+    }
+
+    phenomena.sort_by(|a, b| a.JD.partial_cmp(&b.JD).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(phenomena)
+
+}
+
+/// Computes the apparent geocentric ecliptic position of a planet, and
+/// its distance from the Earth
+#[inline]
+fn planet_point(planet: &planet::Planet, JD: f64) -> (coords::EclPoint, f64) {
+
+    planet::apprnt_ecl_coords(planet, JD)
+
+}
+
+/// Computes the apparent geocentric ecliptic position of the Sun, and
+/// its distance from the Earth
+fn sun_point(JD: f64) -> (coords::EclPoint, f64) {
+
+    let (point, dist) = sun::geocent_ecl_pos(JD);
+    let (long, lat) = sun::ecl_coords_to_FK5(JD, point.long, point.lat);
+    let (nut_in_long, _) = nutation::nutation(JD);
+
+    let apprnt_point = coords::EclPoint {
+        long: long + nut_in_long + aberr::sol_aberr(dist),
+        lat
+    };
+
+    (apprnt_point, dist)
+
+}
+
+fn planet_long(planet: &planet::Planet, JD: f64) -> f64 {
+
+    planet_point(planet, JD).0.long
+
+}
+
+fn diff_in_long(planet: &planet::Planet, JD: f64) -> f64 {
+
+    planet_long(planet, JD) - sun_point(JD).0.long
+
+}
+
+fn elong(planet: &planet::Planet, JD: f64) -> f64 {
+
+    let planet_point = planet_point(planet, JD).0;
+    let sun_point = sun_point(JD).0;
+
+    angle::anglr_sepr(
+        Radians(planet_point.long), Radians(planet_point.lat),
+        Radians(sun_point.long), Radians(sun_point.lat)
+    )
+
+}
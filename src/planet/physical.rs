@@ -0,0 +1,265 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Physical ephemerides of the planets from the IAU rotation elements
+
+use angle;
+use angle::Angle;
+use angle::Radians;
+use coords;
+use error::Error;
+use nutation;
+use planet;
+use precess;
+
+/// Holds a planet's ephemeris values for physical observations
+#[derive(Debug)]
+pub struct Ephemeris {
+    /// Planetographic latitude of the Earth
+    pub De: f64,
+    /// Planetographic latitude of the Sun
+    pub Ds: f64,
+    /// Geocentric position angle of the planet's north pole, or also
+    /// called the position angle of the axis
+    pub P : f64,
+    /// Planetographic longitude of the Earth, or longitude of the
+    /// central meridian
+    pub w : f64,
+    /// Planetographic longitude of the Sun
+    pub ws: f64,
+    /// Phase angle
+    pub i : f64,
+    /// Angular amount of the greatest defect of illumination
+    pub q : f64,
+    /// Apparent equatorial diameter of the planet
+    pub d : f64,
+}
+
+/**
+Computes quantities used in the ephemeris for physical observations of
+a planet
+
+# Returns
+
+* `ephemeris`: The planet's ephemeris. *All angles are in radians*
+
+The planet's north pole and prime meridian are from the rotation
+elements of the IAU Working Group on Cartographic Coordinates and
+Rotational Elements (Archinal et al., 2011). Planetographic longitudes
+increase in the direction opposite to the planet's rotation, that is,
+westwards for Mercury, Mars, Jupiter, Saturn and Neptune, and eastwards
+for Venus and Uranus. For Jupiter and Saturn the longitudes are in
+System III.
+
+`De` and `Ds` are planetographic latitudes, measured from the normal to
+the planet's reference ellipsoid (the radii from `radii()`), so that
+`tan De = tan De' * (a/b)^2`, where `De'` is the planet-centric
+declination of the Earth used by Meeus, and `a` and `b` are the
+equatorial and polar radii.
+
+The position of the planet is corrected for light-time, and `P` is
+referred to the true equator of the date.
+
+# Arguments
+
+* `planet`     : Any variant of [Planet](../enum.Planet.html) except
+                 `Planet::Earth`
+* `JD`         : Julian (Ephemeris) day
+* `mn_oblq`    : Mean obliquity of the ecliptic on `JD` *| in radians*
+* `nut_in_long`: Nutation in ecliptic longitude on `JD` *| in radians*
+* `nut_in_oblq`: Nutation in obliquity of the ecliptic on `JD` *| in radians*
+**/
+pub fn ephemeris<A: Angle, B: Angle, C: Angle> (
+
+    planet      : &planet::Planet,
+    JD          : f64,
+    mn_oblq     : A,
+    nut_in_long : B,
+    nut_in_oblq : C
+
+) -> Result<Ephemeris, Error> {
+
+    let (mn_oblq, nut_in_long, nut_in_oblq) =
+        (mn_oblq.rad(), nut_in_long.rad(), nut_in_oblq.rad());
+
+    if *planet == planet::Planet::Earth {
+        return Err(Error::InvalidPlanet {
+            planet: *planet,
+            function: "planet::physical::ephemeris"
+        });
+    }
+
+    let earth = eq_rect_coords_J2000(planet::heliocent_rect_coords_J2000(&planet::Planet::Earth, JD));
+
+    let mut light_time = 0.0;
+    let mut helio = (0.0, 0.0, 0.0);
+    let mut geo = (0.0, 0.0, 0.0);
+    for _ in 0..2 {
+        helio = eq_rect_coords_J2000(planet::heliocent_rect_coords_J2000(planet, JD - light_time));
+        geo = (helio.0 - earth.0, helio.1 - earth.1, helio.2 - earth.2);
+        light_time = planet::light_time(norm(geo));
+    }
+    let dist = norm(geo);
+    let r = norm(helio);
+
+    let (asc0, dec0, W, retrograde) = rotation_elements(planet, JD - light_time);
+    let (eq_radius, pol_radius) = radii(planet);
+    let flattening_fac = (eq_radius / pol_radius).powi(2);
+
+    // The planet's north pole, the ascending node of its equator on the
+    // equator of J2000.0, and the direction at right angles to both
+    let pole = (dec0.cos()*asc0.cos(), dec0.cos()*asc0.sin(), dec0.sin());
+    let node = (-asc0.sin(), asc0.cos(), 0.0);
+    let node_90 = cross(pole, node);
+
+    let planetographic = |v: (f64, f64, f64)| {
+        let (x, y, z) = (dot(v, node), dot(v, node_90), dot(v, pole));
+        let long_east = y.atan2(x) - W;
+        let long = if retrograde { long_east } else { -long_east };
+        let planetocent_lat = (z / norm(v)).asin();
+        (angle::limit_to_two_PI(long), (planetocent_lat.tan() * flattening_fac).atan())
+    };
+
+    let to_earth = (-geo.0, -geo.1, -geo.2);
+    let to_sun = (-helio.0, -helio.1, -helio.2);
+    let (w, De) = planetographic(to_earth);
+    let (ws, Ds) = planetographic(to_sun);
+
+    let i = (dot(to_earth, to_sun) / (dist * r)).acos();
+
+    let d = 2.0 * (eq_radius / (dist * AU)).asin();
+    let q = (1.0 - (1.0 + i.cos())/2.0) * d;
+
+    let asc = geo.1.atan2(geo.0);
+    let dec = (geo.2 / dist).asin();
+    let true_oblq = mn_oblq + nut_in_oblq;
+    let of_date = |asc: f64, dec: f64| {
+        let (asc, dec) = precess::precess_eq_coords(Radians(asc), Radians(dec), 2451545.0, JD);
+        let (nut_asc, nut_dec) = nutation::nutation_in_eq_coords(
+            &coords::EqPoint { asc, dec }, nut_in_long, nut_in_oblq, true_oblq
+        );
+        (asc + nut_asc, dec + nut_dec)
+    };
+    let (asc1, dec1) = of_date(asc, dec);
+    let (asc01, dec01) = of_date(asc0, dec0);
+
+    let P = (dec01.cos() * (asc01 - asc1).sin()).atan2 (
+        dec01.sin() * dec1.cos()
+      - dec01.cos() * dec1.sin() * (asc01 - asc1).cos()
+    );
+
+    Ok(Ephemeris {
+        De,
+        Ds,
+        P,
+        w,
+        ws,
+        i,
+        q,
+        d
+    })
+
+}
+
+/**
+Computes the equatorial and polar radii of a planet
+
+# Returns
+
+`(eq_radius, pol_radius)`
+
+* `eq_radius` : Equatorial radius of the planet *| in kilometers*
+* `pol_radius`: Polar radius of the planet *| in kilometers*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](../enum.Planet.html)
+**/
+pub fn radii(planet: &planet::Planet) -> (f64, f64) {
+
+    match *planet {
+        planet::Planet::Mercury => (2439.7,  2439.7),
+        planet::Planet::Venus   => (6051.8,  6051.8),
+        planet::Planet::Earth   => (6378.1366, 6356.7519),
+        planet::Planet::Mars    => (3396.19, 3376.20),
+        planet::Planet::Jupiter => (71492.0, 66854.0),
+        planet::Planet::Saturn  => (60268.0, 54364.0),
+        planet::Planet::Uranus  => (25559.0, 24973.0),
+        planet::Planet::Neptune => (24764.0, 24341.0),
+    }
+
+}
+
+/// Astronomical unit, in kilometers
+const AU: f64 = 149597870.7;
+
+/// Returns the right ascension and declination of the north pole
+/// referred to the equator and equinox of J2000.0, the angle of the
+/// prime meridian, and whether the rotation is retrograde
+fn rotation_elements(planet: &planet::Planet, JD: f64) -> (f64, f64, f64, bool) {
+
+    let d = JD - 2451545.0;
+    let T = d / 36525.0;
+
+    let (asc0, dec0, W, retrograde) = match *planet {
+        planet::Planet::Mercury => (281.0097 - 0.0328*T, 61.4143 - 0.0049*T, 329.5469 + 6.1385025*d, false),
+        planet::Planet::Venus   => (272.76, 67.16, 160.20 - 1.4813688*d, true),
+        planet::Planet::Mars    => (317.68143 - 0.1061*T, 52.88650 - 0.0609*T, 176.630 + 350.89198226*d, false),
+        planet::Planet::Jupiter => (268.056595 - 0.006499*T, 64.495303 + 0.002413*T, 284.95 + 870.5360000*d, false),
+        planet::Planet::Saturn  => (40.589 - 0.036*T, 83.537 - 0.004*T, 38.90 + 810.7939024*d, false),
+        planet::Planet::Uranus  => (257.311, -15.175, 203.81 - 501.1600928*d, true),
+        planet::Planet::Neptune => {
+            let N = (357.85 + 52.316*T).to_radians();
+            (299.36 + 0.70*N.sin(), 43.46 - 0.51*N.cos(), 253.18 + 536.3128492*d - 0.48*N.sin(), false)
+        },
+        planet::Planet::Earth   => (-0.641*T, 90.0 - 0.557*T, 190.147 + 360.9856235*d, false),
+    };
+
+    (asc0.to_radians(), dec0.to_radians(), angle::limit_to_360(W).to_radians(), retrograde)
+
+}
+
+fn eq_rect_coords_J2000((x, y, z): (f64, f64, f64)) -> (f64, f64, f64) {
+
+    let oblq = 23.4392911_f64.to_radians();
+
+    (x, y*oblq.cos() - z*oblq.sin(), y*oblq.sin() + z*oblq.cos())
+
+}
+
+fn dot(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+
+    a.0*b.0 + a.1*b.1 + a.2*b.2
+
+}
+
+fn cross(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+
+    (a.1*b.2 - a.2*b.1, a.2*b.0 - a.0*b.2, a.0*b.1 - a.1*b.0)
+
+}
+
+fn norm(a: (f64, f64, f64)) -> f64 {
+
+    dot(a, a).sqrt()
+
+}
@@ -23,6 +23,7 @@ THE SOFTWARE.
 //! Saturn
 
 use angle;
+use angle::Angle;
 
 pub mod moon;
 pub mod ring;
@@ -43,7 +44,9 @@ Computes Saturn's apparent magnitude using G. Muller's formula
             Saturn's ring *| in radians*
 * `B`     : Saturnicentric latitude of the Earth *| in radians*
 **/
-pub fn apprnt_mag_muller(delta: f64, r: f64, delU: f64, B: f64) -> f64 {
+pub fn apprnt_mag_muller<T: Angle, U: Angle>(delta: f64, r: f64, delU: T, B: U) -> f64 {
+
+    let (delU, B) = (delU.rad(), B.rad());
 
     - 8.68
     + 5.0*(r*delta).log10()
@@ -70,7 +73,9 @@ Almanac's formula adopted in 1984
             Saturn's ring *| in radians*
 * `B`     : Saturnicentric latitude of the Earth *| in radians*
 **/
-pub fn apprnt_mag_84(delta: f64, r: f64, delU: f64, B: f64) -> f64 {
+pub fn apprnt_mag_84<T: Angle, U: Angle>(delta: f64, r: f64, delU: T, B: U) -> f64 {
+
+    let (delU, B) = (delU.rad(), B.rad());
 
     - 8.88
     + 5.0*(r*delta).log10()
@@ -106,7 +111,9 @@ Computes Saturn's polar semidiameter
 * `saturn_earth_dist`: Saturn-Earth distance *| in AU*
 * `earth_lat`        : Saturnicentric latitude of Earth *| in radians*
 **/
-pub fn pol_semidiameter(saturn_earth_dist: f64, earth_lat: f64) -> f64 {
+pub fn pol_semidiameter<A: Angle>(saturn_earth_dist: f64, earth_lat: A) -> f64 {
+
+    let earth_lat = earth_lat.rad();
 
     let a = equatorial_unit_semidiameter();
     let b = polar_unit_semidiameter();
@@ -22,6 +22,7 @@ THE SOFTWARE.
 
 //! Eight moons of Saturn
 
+use angle::Radians;
 use planet;
 use precess;
 use time;
@@ -82,7 +83,7 @@ pub fn apprnt_rect_coords(JD: f64, moon: &Moon) -> (f64, f64, f64) {
     let (lambda0, beta0) = (planet_ecl_point.long, planet_ecl_point.lat);
 
     let (lambda0, beta0) = precess::precess_ecl_coords(
-        lambda0, beta0,
+        Radians(lambda0), Radians(beta0),
         JD,
         time::julian_day(
             &time::Date {
@@ -23,8 +23,14 @@ THE SOFTWARE.
 //! The ring system of Saturn
 
 use angle;
+use angle::Angle;
+use angle::Radians;
 use coords;
+use ecliptic;
+use nutation;
 use planet;
+use search;
+use std;
 use time;
 
 #[inline]
@@ -87,7 +93,9 @@ Computes the elements for the ring system of Saturn
 * `nut_in_long`: Nutation in longitude on `JD` *| in radians*
 * `true_oblq`  : True obliquity of the ecliptic on `JD` *| in radians*
 **/
-pub fn elements(JD: f64, nut_in_long: f64, true_oblq: f64) -> Elements {
+pub fn elements<A: Angle, B: Angle>(JD: f64, nut_in_long: A, true_oblq: B) -> Elements {
+
+    let (nut_in_long, true_oblq) = (nut_in_long.rad(), true_oblq.rad());
 
     let (l0, b0, R) = planet::heliocent_coords(&planet::Planet::Earth, JD);
 
@@ -158,10 +166,10 @@ pub fn elements(JD: f64, nut_in_long: f64, true_oblq: f64) -> Elements {
     lambda0 += nut_in_long;
     lambda  += nut_in_long;
 
-    let asc0 = coords::asc_frm_ecl(lambda0, beta0, true_oblq);
-    let dec0 = coords::dec_frm_ecl(lambda0, beta0, true_oblq);
-    let asc = coords::asc_frm_ecl(lambda, beta, true_oblq);
-    let dec = coords::dec_frm_ecl(lambda, beta, true_oblq);
+    let asc0 = coords::asc_frm_ecl(Radians(lambda0), Radians(beta0), Radians(true_oblq));
+    let dec0 = coords::dec_frm_ecl(Radians(lambda0), Radians(beta0), Radians(true_oblq));
+    let asc = coords::asc_frm_ecl(Radians(lambda), Radians(beta), Radians(true_oblq));
+    let dec = coords::dec_frm_ecl(Radians(lambda), Radians(beta), Radians(true_oblq));
 
     let P = (dec0.cos() * (asc0 - asc).sin())
             .atan2(dec0.sin()*dec.cos() - dec0.cos()*dec.sin()*(asc0 - asc).cos());
@@ -204,3 +212,142 @@ pub fn inn_edge_dusk_ring(a: f64, b: f64) -> (f64, f64) {
     (a * 0.5486, b * 0.5486)
 
 }
+
+/// Represents a kind of event in the visibility of Saturn's ring
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RingEventType {
+    /// The Earth crosses the plane of the ring, `B` = 0, and the ring
+    /// is seen edge-on
+    EarthCrossing,
+    /// The Sun crosses the plane of the ring, `B1` = 0, at Saturn's
+    /// equinox
+    SunCrossing,
+    /// The ring is seen at its widest, `|B|` greatest, between two
+    /// equinoxes of Saturn
+    MaxOpening
+}
+
+/// Represents an event in the visibility of Saturn's ring
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RingEvent {
+    /// Type of the event
+    pub event_type: RingEventType,
+    /// Julian (Ephemeris) day of the event
+    pub JD: f64,
+    /// Saturnicentric latitude of the Earth, referred to the plane of
+    /// the ring, at the time *| in radians*
+    pub B: f64
+}
+
+/**
+Finds the ring-plane crossings of the Earth and the Sun, and the times
+of greatest ring opening, in a time range
+
+# Returns
+
+* `events`: Every event between `JD_start` and `JD_end`, sorted by
+            time
+
+The Earth crosses the ring plane either once or three times near each
+of Saturn's equinoxes, about every 15 years. Two of three crossings may
+be only days apart, when the Earth's path is nearly tangent to the ring
+plane; they are found about each minimum of `|B|` between the samples
+of the search.
+
+Because of the Earth's motion around the Sun, `|B|` has a maximum every
+year; only the greatest of these in each half of Saturn's orbit is
+returned, and so `MaxOpening` is found only if no greater opening falls
+within seven years of it, inside or outside the range.
+
+# Arguments
+
+* `JD_start`: Julian (Ephemeris) day at which to start searching
+* `JD_end`  : Julian (Ephemeris) day at which to stop searching
+**/
+pub fn events(JD_start: f64, JD_end: f64) -> Vec<RingEvent> {
+
+    let elements_on = |JD: f64| {
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        elements(JD, nut_in_long, ecliptic::mn_oblq_laskar(JD) + nut_in_oblq)
+    };
+    let B = |JD: f64| elements_on(JD).B;
+    let B1 = |JD: f64| elements_on(JD).B1;
+    let neg_abs_B = |JD: f64| -B(JD).abs();
+
+    let step = 10.0;
+    let half_season = 2700.0;
+    let (search_start, search_end) = (JD_start - half_season, JD_end + half_season);
+    let n = ((search_end - search_start).max(0.0) / step).ceil() as usize;
+
+    let times: Vec<f64> = (0..(n + 1))
+        .map(|i| (search_start + (i as f64)*step).min(search_end))
+        .collect();
+    let elems: Vec<(f64, f64)> = times.iter()
+        .map(|&JD| { let e = elements_on(JD); (e.B, e.B1) })
+        .collect();
+
+    let mut events = Vec::new();
+    let mut maxima = Vec::new();
+
+    for i in 0..n {
+        if times[i + 1] < JD_start || times[i] > JD_end {
+            continue;
+        }
+        if elems[i].0 * elems[i + 1].0 <= 0.0 && elems[i].0 != 0.0 {
+            if let Some(JD) = search::bisect(&B, times[i], times[i + 1]) {
+                if JD >= JD_start && JD <= JD_end {
+                    events.push(RingEvent { event_type: RingEventType::EarthCrossing, JD, B: B(JD) });
+                }
+            }
+        }
+        if elems[i].1 * elems[i + 1].1 <= 0.0 && elems[i].1 != 0.0 {
+            if let Some(JD) = search::bisect(&B1, times[i], times[i + 1]) {
+                if JD >= JD_start && JD <= JD_end {
+                    events.push(RingEvent { event_type: RingEventType::SunCrossing, JD, B: B(JD) });
+                }
+            }
+        }
+    }
+
+    for i in 1..n {
+        let abs_B = elems[i].0.abs();
+        if abs_B > elems[i - 1].0.abs() && abs_B >= elems[i + 1].0.abs() {
+            maxima.push((i, abs_B));
+        }
+
+        // Near a tangent crossing the Earth can pass through the ring
+        // plane and back between two samples, which then have the same
+        // sign; B has an extremum between the two crossings, so a sampled
+        // minimum of |B| is searched for a change of sign
+        let same_sign = elems[i - 1].0 * elems[i].0 > 0.0 && elems[i].0 * elems[i + 1].0 > 0.0;
+        if same_sign && abs_B < elems[i - 1].0.abs() && abs_B <= elems[i + 1].0.abs() {
+            let sign = elems[i].0.signum();
+            let JD_min = search::golden_section_min(|JD| sign * B(JD), times[i - 1], times[i + 1]);
+            if B(JD_min) * elems[i].0 <= 0.0 {
+                for &(a, b) in &[(times[i - 1], JD_min), (JD_min, times[i + 1])] {
+                    if let Some(JD) = search::bisect(&B, a, b) {
+                        if JD >= JD_start && JD <= JD_end {
+                            events.push(RingEvent { event_type: RingEventType::EarthCrossing, JD, B: B(JD) });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for &(i, abs_B) in &maxima {
+        let greatest = maxima.iter().all(|&(j, other)| {
+            (times[j] - times[i]).abs() > half_season || other <= abs_B
+        });
+        if greatest {
+            let JD = search::golden_section_min(neg_abs_B, times[i - 1], times[i + 1]);
+            if JD >= JD_start && JD <= JD_end {
+                events.push(RingEvent { event_type: RingEventType::MaxOpening, JD, B: B(JD) });
+            }
+        }
+    }
+
+    events.sort_by(|a, b| a.JD.partial_cmp(&b.JD).unwrap_or(std::cmp::Ordering::Equal));
+
+    events
+
+}
@@ -21,8 +21,15 @@ THE SOFTWARE.
 */
 
 //! Corrections for precession
+//!
+//! Coordinates documented as *in radians* are taken as any
+//! [Angle](../angle/trait.Angle.html), such as
+//! [Radians](../angle/struct.Radians.html) or
+//! [Degrees](../angle/struct.Degrees.html); plain `f64` values are
+//! taken to be in radians.
 
 use angle;
+use angle::Angle;
 use std;
 use time;
 
@@ -54,8 +61,9 @@ the equatorial coordinates to the new epoch.
          Shouldn't be more than a few hundred years away from
          the old epoch.
 **/
-pub fn annual_precess(asc: f64, dec: f64, JD: f64) -> (f64, f64)
+pub fn annual_precess<A: Angle, B: Angle>(asc: A, dec: B, JD: f64) -> (f64, f64)
 {
+    let (asc, dec) = (asc.rad(), dec.rad());
     let JC = time::julian_cent(JD);
 
     let m = (
@@ -90,11 +98,12 @@ Computes equatorial coordinates reduced to a different epoch
 * `JD1`    : Julian (Ephemeris) day corresponding to the old epoch
 * `JD2`    : Julian (Ephemeris) day corresponding to the new epoch
 **/
-pub fn precess_eq_coords(old_asc: f64,
-                         old_dec: f64,
-                         JD1: f64,
-                         JD2: f64) -> (f64, f64)
+pub fn precess_eq_coords<A: Angle, B: Angle>(old_asc: A,
+                                             old_dec: B,
+                                             JD1: f64,
+                                             JD2: f64) -> (f64, f64)
 {
+    let (old_asc, old_dec) = (old_asc.rad(), old_dec.rad());
     let T = time::julian_cent(JD1);
     let t = (JD2 - JD1) / 36525.0;
 
@@ -148,11 +157,12 @@ FK4 system, reduced to a different epoch
 * `JD1`    : Julian (Ephemeris) day corresponding to the old epoch
 * `JD2`    : Julian (Ephemeris) day corresponding to the new epoch
 **/
-pub fn precess_eq_coords_FK5(old_asc: f64,
-                             old_dec: f64,
-                             JD1: f64,
-                             JD2: f64) -> (f64, f64)
+pub fn precess_eq_coords_FK5<A: Angle, B: Angle>(old_asc: A,
+                                                 old_dec: B,
+                                                 JD1: f64,
+                                                 JD2: f64) -> (f64, f64)
 {
+    let (old_asc, old_dec) = (old_asc.rad(), old_dec.rad());
     let T = (JD1 - 2415020.3135) / 36524.2199;
     let t = (JD2 - JD1) / 36524.2199;
 
@@ -208,11 +218,12 @@ Computes ecliptic coordinates reduced to a different epoch
 * `JD_old`  : Julian (Ephemeris) day corresponding to the old epoch
 * `JD_new`  : Julian (Ephemeris) day corresponding to the new epoch
 **/
-pub fn precess_ecl_coords(old_long: f64,
-                          old_lat: f64,
-                          JD_old: f64,
-                          JD_new: f64) -> (f64, f64)
+pub fn precess_ecl_coords<A: Angle, B: Angle>(old_long: A,
+                                              old_lat: B,
+                                              JD_old: f64,
+                                              JD_new: f64) -> (f64, f64)
 {
+    let (old_long, old_lat) = (old_long.rad(), old_lat.rad());
     let T = time::julian_cent(JD_old);
     let t = (JD_new - JD_old) / 36525.0;
 
@@ -0,0 +1,87 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Root and extremum finding for the searches in this crate
+
+/// Finds a root of `f` in `[a, b]` by bisection, if `f` changes sign
+/// in the interval
+pub fn bisect<F: Fn(f64) -> f64>(f: &F, mut a: f64, mut b: f64) -> Option<f64> {
+
+    let mut fa = f(a);
+    if fa * f(b) > 0.0 {
+        return None;
+    }
+
+    for _ in 0..50 {
+        let m = (a + b) / 2.0;
+        let fm = f(m);
+        if fa * fm <= 0.0 {
+            b = m;
+        } else {
+            a = m;
+            fa = fm;
+        }
+    }
+
+    Some((a + b) / 2.0)
+
+}
+
+/// Finds the minimum of a unimodal function `f` in `[a, b]`
+pub fn golden_section_min<F: Fn(f64) -> f64>(f: F, mut a: f64, mut b: f64) -> f64 {
+
+    let r = (5_f64.sqrt() - 1.0) / 2.0;
+
+    let mut c = b - r*(b - a);
+    let mut d = a + r*(b - a);
+
+    for _ in 0..60 {
+        if f(c) < f(d) { b = d; }
+        else           { a = c; }
+        c = b - r*(b - a);
+        d = a + r*(b - a);
+    }
+
+    (a + b) / 2.0
+
+}
+
+/// Finds the least of the minima of `f` in `[a, b]`, by sampling it at
+/// `n` steps and then refining the least sample
+pub fn scan_min<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> f64 {
+
+    let step = (b - a) / (n as f64);
+
+    let mut t_min = a;
+    let mut f_min = f64::MAX;
+    for i in 0..(n + 1) {
+        let t = a + (i as f64)*step;
+        let f_t = f(t);
+        if f_t < f_min {
+            t_min = t;
+            f_min = f_t;
+        }
+    }
+
+    golden_section_min(f, t_min - step, t_min + step)
+
+}
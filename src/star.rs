@@ -22,6 +22,78 @@ THE SOFTWARE.
 
 //! Stars
 
+use aberr;
+use coords;
+use ecliptic;
+use nutation;
+use precess;
+
+/// Represents a fixed star and its proper motion
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Star {
+    /// Right ascension at `JD_epoch`, referred to the FK5 system *| in radians*
+    pub asc: f64,
+    /// Declination at `JD_epoch`, referred to the FK5 system *| in radians*
+    pub dec: f64,
+    /// Distance of the star *| in parsecs*. Any positive value will do
+    /// when `radial_vel` is zero.
+    pub dist: f64,
+    /// Radial velocity of the star *| in parsecs per year*
+    pub radial_vel: f64,
+    /// Annual proper motion in right ascension, in seconds of time
+    /// expressed as arcseconds *| in radians*, as taken by
+    /// [eq_coords_frm_motion()](./fn.eq_coords_frm_motion.html)
+    pub pmotion_asc: f64,
+    /// Annual proper motion in declination *| in radians*
+    pub pmotion_dec: f64,
+    /// Julian (Ephemeris) day of the epoch and equinox of the position
+    pub JD_epoch: f64
+}
+
+/**
+Computes the apparent place of a star, referred to the true equator
+and equinox of the date
+
+# Returns
+
+* `eq_point`: Equatorial point of the star *| in radians*
+
+The star is moved along its proper motion from its epoch, precessed to
+the mean equinox of the date, and corrected for nutation and annual
+aberration, as in Meeus, chapter 23.
+
+# Arguments
+
+* `star`: The [Star](./struct.Star.html)
+* `JD`  : Julian (Ephemeris) day
+**/
+pub fn apprnt_eq_coords(star: &Star, JD: f64) -> coords::EqPoint
+{
+    let (asc, dec) = eq_coords_frm_motion(
+        star.asc,
+        star.dec,
+        star.dist,
+        star.radial_vel,
+        star.pmotion_asc,
+        star.pmotion_dec,
+        (JD - star.JD_epoch) / 365.25
+    );
+    let (asc, dec) = precess::precess_eq_coords(asc, dec, star.JD_epoch, JD);
+    let mn_point = coords::EqPoint { asc, dec };
+
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+    let (nut_asc, nut_dec) = nutation::nutation_in_eq_coords(
+        &mn_point, nut_in_long, nut_in_oblq, true_oblq
+    );
+    let (abrr_asc, abrr_dec) = aberr::stell_aberr_frm_earth_vel(&mn_point, JD);
+
+    coords::EqPoint {
+        asc: asc + nut_asc + abrr_asc,
+        dec: dec + nut_dec + abrr_dec
+    }
+}
+
 /**
 Computes the combined magnitude of two stars
 
@@ -159,8 +231,9 @@ it's proper motion, distance and radial velocity.
 * `asc0`: Right ascension of the star initially *| in radians*
 * `dec0`: Declination of the star initially *| in radians*
 * `r`: Distance of the star (*parsecs*)
-* `delta_r`: Radial velocity of the star (*parsecs/second*)
-* `proper_motion_asc`: Proper motion of the star in right ascension
+* `delta_r`: Radial velocity of the star (*parsecs/year*)
+* `proper_motion_asc`: Proper motion of the star in right ascension,
+                           in seconds of time expressed as arcseconds
                            *| in radians*
 * `proper_motion_dec`: Proper motion of the star in declination
                            *| in radians*
@@ -21,12 +21,23 @@ THE SOFTWARE.
 */
 
 //! The Sun
+//!
+//! The rectangular coordinates for J2000.0, B1950.0 and other equinoxes
+//! are all in the FK5 system. No transformation to the FK4 system (its
+//! E-terms of aberration and equinox correction) is provided, so the
+//! B1950.0 coordinates can't be compared directly with FK4 catalogues.
 
+pub mod season;
+
+use aberr;
 use angle;
+use angle::Radians;
 use time;
 use std;
 use planet;
 use coords;
+use ecliptic;
+use nutation;
 
 /**
 Computes the Sun's equatorial semidiameter
@@ -70,6 +81,43 @@ pub fn geocent_ecl_pos(JD: f64) -> (coords::EclPoint, f64) {
 
 }
 
+/**
+Computes the Sun's apparent geocentric equatorial position, referred
+to the true equator and equinox of the date
+
+# Returns
+
+`(sun_eq_point, sun_earth_dist)`
+
+* `sun_eq_point`  : Equatorial point of the Sun *| in radians*,
+                    converted to the FK5 system and corrected for
+                    nutation and aberration
+* `sun_earth_dist`: Sun-Earth distance *| in AU*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apprnt_eq_pos(JD: f64) -> (coords::EqPoint, f64) {
+
+    let (sun_ecl_point, rad_vec) = geocent_ecl_pos(JD);
+    let (long_FK5, lat_FK5) = ecl_coords_to_FK5(
+        JD, sun_ecl_point.long, sun_ecl_point.lat
+    );
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let long = long_FK5 + nut_in_long + aberr::sol_aberr(rad_vec);
+
+    let eq_point = coords::EqPoint {
+        asc: coords::asc_frm_ecl(Radians(long), Radians(lat_FK5), Radians(true_oblq)),
+        dec: coords::dec_frm_ecl(Radians(long), Radians(lat_FK5), Radians(true_oblq))
+    };
+
+    (eq_point, rad_vec)
+
+}
+
 /**
 Computes the Sun's geocentric ecliptic coordinates converted to the
 FK5 system
@@ -167,6 +215,132 @@ pub fn geocent_rect_coords (
 
 }
 
+/**
+Computes the Sun's geocentric rectangular coordinates, referred to the
+mean equator and equinox of J2000.0
+
+# Returns
+
+`(x, y z)`
+
+* `x`: The X coordinate *| in AU*
+* `y`: The Y coordinate *| in AU*
+* `z`: The Z coordinate *| in AU*
+
+The axes are those of [geocent_rect_coords()](./fn.geocent_rect_coords.html),
+but for the FK5 equator and equinox of J2000.0. The Earth's position
+is that of [planet::heliocent_coords_J2000()](../planet/fn.heliocent_coords_J2000.html),
+which stands in for VSOP87B by precessing VSOP87D. The coordinates here,
+and those of the functions built on them, inherit its error, which
+hasn't been checked against the VSOP87B tables.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn geocent_rect_coords_J2000(JD: f64) -> (f64, f64, f64) {
+
+    let (x, y, z) = geocent_ecl_rect_coords_J2000(JD);
+
+    (
+        x + 0.000000440360*y - 0.000000190919*z,
+       -0.000000479966*x + 0.917482137087*y - 0.397776982902*z,
+        0.397776982902*y + 0.917482137087*z
+    )
+
+}
+
+/**
+Computes the Sun's geocentric rectangular coordinates, referred to the
+mean equator and equinox of B1950.0
+
+# Returns
+
+`(x, y z)`
+
+* `x`: The X coordinate *| in AU*
+* `y`: The Y coordinate *| in AU*
+* `z`: The Z coordinate *| in AU*
+
+The axes are those of [geocent_rect_coords()](./fn.geocent_rect_coords.html),
+but for the mean equator and equinox of B1950.0 (FK5 precession).
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn geocent_rect_coords_B1950(JD: f64) -> (f64, f64, f64) {
+
+    let (x, y, z) = geocent_ecl_rect_coords_J2000(JD);
+
+    (
+        0.999925702634*x + 0.012189716217*y + 0.000011134016*z,
+       -0.011179418036*x + 0.917413998946*y - 0.397777041885*z,
+       -0.004859003787*x + 0.397747363646*y + 0.917482111428*z
+    )
+
+}
+
+/**
+Computes the Sun's geocentric rectangular coordinates, referred to the
+mean equator and equinox of any epoch
+
+# Returns
+
+`(x, y z)`
+
+* `x`: The X coordinate *| in AU*
+* `y`: The Y coordinate *| in AU*
+* `z`: The Z coordinate *| in AU*
+
+The coordinates of [geocent_rect_coords_J2000()](./fn.geocent_rect_coords_J2000.html)
+are precessed to the equinox of `JD_equinox`.
+
+# Arguments
+
+* `JD`        : Julian (Ephemeris) day
+* `JD_equinox`: Julian (Ephemeris) day of the equinox to refer
+                the coordinates to
+**/
+pub fn geocent_rect_coords_frm_J2000(JD: f64, JD_equinox: f64) -> (f64, f64, f64) {
+
+    let (x0, y0, z0) = geocent_rect_coords_J2000(JD);
+
+    let t = time::julian_cent(JD_equinox);
+    let zeta = angle::deg_frm_dms(0, 0, t*(2306.2181 + t*(0.30188 + t*0.017998))).to_radians();
+    let z = angle::deg_frm_dms(0, 0, t*(2306.2181 + t*(1.09468 + t*0.018203))).to_radians();
+    let theta = angle::deg_frm_dms(0, 0, t*(2004.3109 - t*(0.42665 + t*0.041833))).to_radians();
+
+    let xx = zeta.cos()*z.cos()*theta.cos() - zeta.sin()*z.sin();
+    let xy = zeta.sin()*z.cos() + zeta.cos()*z.sin()*theta.cos();
+    let xz = zeta.cos()*theta.sin();
+    let yx = -zeta.cos()*z.sin() - zeta.sin()*z.cos()*theta.cos();
+    let yy = zeta.cos()*z.cos() - zeta.sin()*z.sin()*theta.cos();
+    let yz = -zeta.sin()*theta.sin();
+    let zx = -z.cos()*theta.sin();
+    let zy = -z.sin()*theta.sin();
+    let zz = theta.cos();
+
+    (
+        xx*x0 + yx*y0 + zx*z0,
+        xy*x0 + yy*y0 + zy*z0,
+        xz*x0 + yz*y0 + zz*z0
+    )
+
+}
+
+fn geocent_ecl_rect_coords_J2000(JD: f64) -> (f64, f64, f64) {
+
+    let (L, B, R) = planet::heliocent_coords_J2000(&planet::Planet::Earth, JD);
+
+    (
+       -R * B.cos() * L.cos(),
+       -R * B.cos() * L.sin(),
+       -R * B.sin()
+    )
+
+}
+
 /**
 Return quantites used in the ephemeris for physical observations of
 the Sun
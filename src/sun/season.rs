@@ -0,0 +1,214 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Equinoxes and solstices
+
+use aberr;
+use angle;
+use nutation;
+use sun;
+use time;
+
+/// Represents an equinox or a solstice
+#[derive(Copy, Clone, Debug)]
+pub enum Season {
+    /// March equinox, when the Sun's apparent longitude is 0 degrees
+    MarEquinox,
+    /// June solstice, when the Sun's apparent longitude is 90 degrees
+    JuneSolstice,
+    /// September equinox, when the Sun's apparent longitude is 180 degrees
+    SeptEquinox,
+    /// December solstice, when the Sun's apparent longitude is 270 degrees
+    DecSolstice,
+}
+
+/**
+Computes the Sun's apparent geocentric longitude
+
+# Returns
+
+* `apprnt_long`: Apparent longitude of the Sun *| in radians*,
+                 converted to the FK5 system and corrected for
+                 nutation and aberration
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apprnt_long(JD: f64) -> f64 {
+
+    let (sun_ecl_point, rad_vec) = sun::geocent_ecl_pos(JD);
+    let (long_FK5, _) = sun::ecl_coords_to_FK5(
+        JD, sun_ecl_point.long, sun_ecl_point.lat
+    );
+    let (nut_in_long, _) = nutation::nutation(JD);
+
+    angle::limit_to_two_PI(long_FK5 + nut_in_long + aberr::sol_aberr(rad_vec))
+
+}
+
+/**
+Computes an approximate time of an equinox or a solstice
+
+# Returns
+
+* `JD`: Julian Ephemeris day of the equinox or solstice
+
+This function evaluates the mean series of *Meeus* and corrects
+them with his 24 periodic terms. Between the years 1951 and 2050,
+`JD` is in error by no more than 51 seconds; for the years -1000
+to +3000 the error stays within two minutes.
+
+# Arguments
+
+* `year`  : Year, in the range -1000 to +3000
+* `season`: The [Season](./enum.Season.html)
+**/
+pub fn approx_time(year: i32, season: &Season) -> f64 {
+
+    let JD0 = mn_time(year, season);
+
+    let T = time::julian_cent(JD0);
+    let W = (35999.373*T - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334*W.cos() + 0.0007*(2.0 * W).cos();
+
+    struct terms(f64, f64, f64);
+    let periodic_terms = [
+        terms(485.0, 324.96,   1934.136),
+        terms(203.0, 337.23,  32964.467),
+        terms(199.0, 342.08,     20.186),
+        terms(182.0,  27.85, 445267.112),
+        terms(156.0,  73.14,  45036.886),
+        terms(136.0, 171.52,  22518.443),
+        terms( 77.0, 222.54,  65928.934),
+        terms( 74.0, 296.72,   3034.906),
+        terms( 70.0, 243.58,   9037.513),
+        terms( 58.0, 119.81,  33718.147),
+        terms( 52.0, 297.17,    150.678),
+        terms( 50.0,  21.02,   2281.226),
+        terms( 45.0, 247.54,  29929.562),
+        terms( 44.0, 325.15,  31555.956),
+        terms( 29.0,  60.93,   4443.417),
+        terms( 18.0, 155.12,  67555.328),
+        terms( 17.0, 288.79,   4562.452),
+        terms( 16.0, 198.04,  62894.029),
+        terms( 14.0, 199.76,  31436.921),
+        terms( 12.0,  95.39,  14577.848),
+        terms( 12.0, 287.11,  31931.756),
+        terms( 12.0, 320.81,  34777.259),
+        terms(  9.0, 227.73,   1222.114),
+        terms(  8.0,  15.45,  16859.074),
+    ];
+
+    let mut S = 0.0;
+    for x in periodic_terms.iter() {
+        S += x.0 * (x.1 + x.2*T).to_radians().cos();
+    }
+
+    JD0 + 0.00001*S/delta_lambda
+
+}
+
+/**
+Computes the time of an equinox or a solstice
+
+# Returns
+
+* `JD`: Julian Ephemeris day of the equinox or solstice
+
+The approximate time from [approx_time()](./fn.approx_time.html) is
+corrected iteratively until the Sun's apparent longitude, computed
+from the full VSOP87 theory, equals that of the `season`. The
+result agrees with the VSOP87 Sun to better than a second of time
+between the years -1000 and +3000.
+
+# Arguments
+
+* `year`  : Year, in the range -1000 to +3000
+* `season`: The [Season](./enum.Season.html)
+**/
+pub fn time(year: i32, season: &Season) -> f64 {
+
+    let mut JD = approx_time(year, season);
+    let k = (*season as u8) as f64;
+
+    for _ in 0..10 {
+        let correction = 58.0 * (k*90_f64.to_radians() - apprnt_long(JD)).sin();
+        JD += correction;
+
+        if correction.abs() < 0.000001 {
+            break;
+        }
+    }
+
+    JD
+
+}
+
+/**
+Computes the times of both equinoxes and both solstices in a year
+
+# Returns
+
+`(mar_equinox, june_solstice, sept_equinox, dec_solstice)`
+
+* `mar_equinox`  : Julian Ephemeris day of the March equinox
+* `june_solstice`: Julian Ephemeris day of the June solstice
+* `sept_equinox` : Julian Ephemeris day of the September equinox
+* `dec_solstice` : Julian Ephemeris day of the December solstice
+
+# Arguments
+
+* `year`: Year, in the range -1000 to +3000
+**/
+pub fn times_in_year(year: i32) -> (f64, f64, f64, f64) {
+
+    (
+        time(year, &Season::MarEquinox),
+        time(year, &Season::JuneSolstice),
+        time(year, &Season::SeptEquinox),
+        time(year, &Season::DecSolstice)
+    )
+
+}
+
+fn mn_time(year: i32, season: &Season) -> f64 {
+
+    if year < 1000 {
+        let Y = (year as f64) / 1000.0;
+        match *season {
+            Season::MarEquinox   => Horner_eval!(Y, 1721139.29189, 365242.1374,  0.06134,  0.00111, -0.00071),
+            Season::JuneSolstice => Horner_eval!(Y, 1721233.25401, 365241.72562, -0.05323, 0.00907,  0.00025),
+            Season::SeptEquinox  => Horner_eval!(Y, 1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074),
+            Season::DecSolstice  => Horner_eval!(Y, 1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006),
+        }
+    } else {
+        let Y = ((year - 2000) as f64) / 1000.0;
+        match *season {
+            Season::MarEquinox   => Horner_eval!(Y, 2451623.80984, 365242.37404,  0.05169, -0.00411, -0.00057),
+            Season::JuneSolstice => Horner_eval!(Y, 2451716.56767, 365241.62603,  0.00325,  0.00888, -0.0003),
+            Season::SeptEquinox  => Horner_eval!(Y, 2451810.21715, 365242.01767, -0.11575,  0.00337,  0.00078),
+            Season::DecSolstice  => Horner_eval!(Y, 2451900.05952, 365242.74049, -0.06223, -0.00823,  0.00032),
+        }
+    }
+
+}
@@ -0,0 +1,574 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Easter, the Jewish and Islamic calendars, and ISO weeks
+
+use error::Error;
+use time;
+use time::{CalType, Date, Month};
+
+/**
+Computes the date of Easter Sunday
+
+# Returns
+
+`(month, day)`
+
+* `month`: Month of Easter Sunday
+* `day`  : Day of the month of Easter Sunday
+
+With `CalType::Gregorian`, the algorithm is valid for all years from
+1583 onwards, and gives the date in the proleptic Gregorian calendar
+for earlier years. With `CalType::Julian`, Easter is computed the way
+the Orthodox churches do, and the date returned is in the Julian
+calendar. Years before 1 are numbered astronomically, so that the year
+0 is 1 BC, and are reduced with Euclidean division and remainder, so
+that the dates repeat with the cycles of the calendars.
+
+# Arguments
+
+* `year`    : Year
+* `cal_type`: `CalType` enum
+**/
+pub fn easter(year: i16, cal_type: &CalType) -> (Month, u8) {
+
+    let y = year as i32;
+
+    let (n, p) = match *cal_type {
+        CalType::Gregorian => {
+            let a = y.rem_euclid(19);
+            let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+            let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+            let f = (b + 8).div_euclid(25);
+            let g = (b - f + 1).div_euclid(3);
+            let h = (19*a + b - d - g + 15).rem_euclid(30);
+            let (i, k) = (c / 4, c % 4);
+            let l = (32 + 2*e + 2*i - h - k).rem_euclid(7);
+            let m = (a + 11*h + 22*l) / 451;
+            ((h + l - 7*m + 114) / 31, (h + l - 7*m + 114) % 31)
+        },
+        CalType::Julian => {
+            let (a, b, c) = (y.rem_euclid(4), y.rem_euclid(7), y.rem_euclid(19));
+            let d = (19*c + 15) % 30;
+            let e = (2*a + 4*b - d + 34) % 7;
+            ((d + e + 114) / 31, (d + e + 114) % 31)
+        },
+    };
+
+    (month_frm_num(n as u8), (p + 1) as u8)
+
+}
+
+/**
+Computes the day of the year of a date
+
+# Returns
+
+* `day_of_year`: Day of the year, `1` for January 1
+
+# Arguments
+
+* `date`: A `Date`
+**/
+pub fn day_of_year(date: &Date) -> u16 {
+
+    let M = date.month as i32;
+    let K = if time::is_leap_year(date.year, &date.cal_type) { 1 } else { 2 };
+
+    (275*M/9 - K*((M + 9)/12) + (date.decimal_day as i32) - 30) as u16
+
+}
+
+/**
+Computes the month and day from the day of the year
+
+# Returns
+
+`(month, day)`
+
+* `month`: Month
+* `day`  : Day of the month
+
+# Arguments
+
+* `year`       : Year
+* `day_of_year`: Day of the year, `1` for January 1
+* `cal_type`   : `CalType` enum
+**/
+pub fn date_frm_day_of_year(year: i16, day_of_year: u16, cal_type: &CalType) -> (Month, u8) {
+
+    let N = day_of_year as i32;
+    let K = if time::is_leap_year(year, cal_type) { 1 } else { 2 };
+
+    let M = if N < 32 { 1 } else { (9.0*((K + N) as f64)/275.0 + 0.98) as i32 };
+    let D = N - 275*M/9 + K*((M + 9)/12) + 30;
+
+    (month_frm_num(M as u8), D as u8)
+
+}
+
+/**
+Computes the ISO 8601 week of a date
+
+# Returns
+
+`(week_year, week)`
+
+* `week_year`: Year the week belongs to, which differs from the
+               calendar year for some days near January 1
+* `week`     : Week of the year, from `1` to `53`. Weeks begin on
+               Monday, and week `1` is the one that contains the
+               year's first Thursday.
+
+# Arguments
+
+* `date`: A `Date`
+**/
+pub fn iso_week(date: &Date) -> (i16, u8) {
+
+    let weekday = iso_weekday(date) as i32;
+    let week = (day_of_year(date) as i32 - weekday + 10) / 7;
+
+    if week < 1 {
+        (date.year - 1, weeks_in_iso_year(date.year - 1, &date.cal_type))
+    } else if week > weeks_in_iso_year(date.year, &date.cal_type) as i32 {
+        (date.year + 1, 1)
+    } else {
+        (date.year, week as u8)
+    }
+
+}
+
+/// Numbers the day of the week from 1 for Monday to 7 for Sunday, in
+/// the calendar of the date
+fn iso_weekday(date: &Date) -> u8 {
+
+    let date_0UT = Date {
+        year: date.year,
+        month: date.month,
+        decimal_day: date.decimal_day.floor(),
+        cal_type: match date.cal_type {
+            CalType::Gregorian => CalType::Gregorian,
+            CalType::Julian    => CalType::Julian,
+        }
+    };
+    let JD = time::julian_day(&date_0UT);
+
+    match ((JD + 1.5).floor() as i64).rem_euclid(7) {
+        0 => 7,
+        wd => wd as u8,
+    }
+
+}
+
+fn weeks_in_iso_year(year: i16, cal_type: &CalType) -> u8 {
+
+    let jan_1 = Date {
+        year,
+        month: Month::Jan,
+        decimal_day: 1.0,
+        cal_type: match *cal_type {
+            CalType::Gregorian => CalType::Gregorian,
+            CalType::Julian    => CalType::Julian,
+        }
+    };
+    let weekday = iso_weekday(&jan_1);
+
+    if weekday == 4 || (weekday == 3 && time::is_leap_year(year, cal_type)) { 53 }
+    else                                                                    { 52 }
+
+}
+
+/**
+Computes the date of the Jewish Passover (Pesach, Nisan 15)
+
+# Returns
+
+`(month, day)`
+
+* `month`: Month of Passover, March or April
+* `day`  : Day of the month of Passover
+
+# Arguments
+
+* `year`    : Year, in the Gregorian or Julian calendar, in which
+              Passover falls
+* `cal_type`: `CalType` enum
+**/
+pub fn passover(year: i16, cal_type: &CalType) -> (Month, u8) {
+
+    let D = passover_march_day(year as i32, cal_type);
+
+    if D > 31 { (Month::Apr, (D - 31) as u8) }
+    else      { (Month::Mar, D as u8) }
+
+}
+
+/**
+Computes the date of the Jewish New Year (Rosh Hashanah, Tishri 1)
+
+# Returns
+
+`(month, day)`
+
+* `month`: Month of Rosh Hashanah, September or October
+* `day`  : Day of the month of Rosh Hashanah
+
+Rosh Hashanah falls 163 days after the Passover of the same year of
+the Gregorian or Julian calendar.
+
+# Arguments
+
+* `year`    : Year, in the Gregorian or Julian calendar, in which
+              Rosh Hashanah falls
+* `cal_type`: `CalType` enum
+**/
+pub fn rosh_hashanah(year: i16, cal_type: &CalType) -> (Month, u8) {
+
+    let D = passover_march_day(year as i32, cal_type) + 163 - 184;
+
+    if D > 30 { (Month::Oct, (D - 30) as u8) }
+    else      { (Month::Sept, D as u8) }
+
+}
+
+// Meeus, chapter 9. Returns the day of March, which may be beyond 31
+fn passover_march_day(X: i32, cal_type: &CalType) -> i32 {
+
+    let S = match *cal_type {
+        CalType::Gregorian => (3*(X/100) - 5) / 4,
+        CalType::Julian    => 0,
+    };
+    let a = (12*X + 12).rem_euclid(19);
+    let b = X.rem_euclid(4);
+    let Q =
+       -1.904412361576
+      + 1.554241796621 * (a as f64)
+      + 0.25 * (b as f64)
+      - 0.003177794022 * (X as f64)
+      + (S as f64);
+    let Q_int = Q.floor() as i32;
+    let j = (Q_int + 3*X + 5*b + 2 - S).rem_euclid(7);
+    let r = Q - Q.floor();
+
+    if j == 2 || j == 4 || j == 6                    { Q_int + 23 }
+    else if j == 1 && a > 6 && r >= 0.632870370      { Q_int + 24 }
+    else if j == 0 && a > 11 && r >= 0.897723765     { Q_int + 23 }
+    else                                             { Q_int + 22 }
+
+}
+
+/// Represents a month in the Jewish calendar
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum JewishMonth {
+    /// Tishri
+    Tishri,
+    /// Heshvan
+    Heshvan,
+    /// Kislev
+    Kislev,
+    /// Tevet
+    Tevet,
+    /// Shevat
+    Shevat,
+    /// Adar I, only in leap years
+    AdarI,
+    /// Adar, or Adar II in leap years
+    Adar,
+    /// Nisan
+    Nisan,
+    /// Iyar
+    Iyar,
+    /// Sivan
+    Sivan,
+    /// Tammuz
+    Tammuz,
+    /// Av
+    Av,
+    /// Elul
+    Elul
+}
+
+/// Represents a date in the Jewish calendar
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JewishDate {
+    /// Year, counted from the Creation (*Anno Mundi*)
+    pub year: i16,
+    /// Month
+    pub month: JewishMonth,
+    /// Day of the month
+    pub day: u8
+}
+
+/**
+Checks if a year of the Jewish calendar is a leap year, with 13 months
+
+# Arguments
+
+* `year`: Jewish year
+**/
+pub fn is_jewish_leap_year(year: i16) -> bool {
+
+    (7*(year as i32) + 1).rem_euclid(19) < 7
+
+}
+
+/**
+Computes the number of days in a year of the Jewish calendar
+
+# Returns
+
+* `days`: `353`, `354` or `355` days in common years and `383`, `384`
+          or `385` days in leap years
+
+# Arguments
+
+* `year`: Jewish year
+**/
+pub fn days_in_jewish_year(year: i16) -> u16 {
+
+    (jewish_new_year_JD(year as i32 + 1) - jewish_new_year_JD(year as i32)) as u16
+
+}
+
+/**
+Computes the Julian day of a date in the Jewish calendar
+
+# Returns
+
+* `JD`: Julian day at 0h UT of the date. The Jewish day begins at
+        the preceding sunset.
+
+# Arguments
+
+* `date`: A [JewishDate](./struct.JewishDate.html)
+**/
+pub fn julian_day_frm_jewish(date: &JewishDate) -> Result<f64, Error> {
+
+    let months = jewish_months(date.year);
+
+    let mut JD = jewish_new_year_JD(date.year as i32);
+    for &(month, days) in &months {
+        if month == date.month {
+            if date.day < 1 || date.day > days {
+                return Err(Error::InvalidDate);
+            }
+            return Ok(JD + (date.day - 1) as f64);
+        }
+        JD += days as f64;
+    }
+
+    Err(Error::InvalidDate)
+
+}
+
+/**
+Computes the date in the Jewish calendar of a Julian day
+
+# Returns
+
+* `date`: The [JewishDate](./struct.JewishDate.html) of the civil day
+          containing the Julian day. The Jewish day begins at the
+          preceding sunset.
+
+# Arguments
+
+* `JD`: Julian day
+**/
+pub fn jewish_frm_julian_day(JD: f64) -> JewishDate {
+
+    let JD = (JD - 0.5).floor() + 0.5;
+
+    let mut year = (((JD - 347997.5) / 365.2468).floor() as i32).max(1);
+    while jewish_new_year_JD(year + 1) <= JD { year += 1; }
+    while jewish_new_year_JD(year) > JD      { year -= 1; }
+
+    let year = year as i16;
+    let mut day = JD - jewish_new_year_JD(year as i32);
+    for &(month, days) in &jewish_months(year) {
+        if day < days as f64 {
+            return JewishDate { year, month, day: day as u8 + 1 };
+        }
+        day -= days as f64;
+    }
+
+    panic!("Internal error in time::calendar::jewish_frm_julian_day")
+
+}
+
+fn jewish_new_year_JD(year: i32) -> f64 {
+
+    // Tishri 1 of a year is 163 days after the Passover of the year
+    // before it, which falls in year - 3761 of the Julian calendar
+    let X = year - 3761;
+    let mar_1 = Date {
+        year: X as i16,
+        month: Month::Mar,
+        decimal_day: 1.0,
+        cal_type: CalType::Julian
+    };
+
+    time::julian_day(&mar_1) + (passover_march_day(X, &CalType::Julian) - 1 + 163) as f64
+
+}
+
+fn jewish_months(year: i16) -> Vec<(JewishMonth, u8)> {
+
+    let (heshvan, kislev) = match days_in_jewish_year(year) % 10 {
+        3 => (29, 29),
+        4 => (29, 30),
+        _ => (30, 30),
+    };
+
+    let mut months = vec![
+        (JewishMonth::Tishri,  30),
+        (JewishMonth::Heshvan, heshvan),
+        (JewishMonth::Kislev,  kislev),
+        (JewishMonth::Tevet,   29),
+        (JewishMonth::Shevat,  30),
+    ];
+    if is_jewish_leap_year(year) {
+        months.push((JewishMonth::AdarI, 30));
+    }
+    months.extend_from_slice(&[
+        (JewishMonth::Adar,   29),
+        (JewishMonth::Nisan,  30),
+        (JewishMonth::Iyar,   29),
+        (JewishMonth::Sivan,  30),
+        (JewishMonth::Tammuz, 29),
+        (JewishMonth::Av,     30),
+        (JewishMonth::Elul,   29),
+    ]);
+
+    months
+
+}
+
+/// Represents a date in the tabular Islamic calendar
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IslamicDate {
+    /// Year, counted from the Hijra (*Anno Hegirae*)
+    pub year: i16,
+    /// Month, from `1` (Muharram) to `12` (Dhu al-Hijja)
+    pub month: u8,
+    /// Day of the month
+    pub day: u8
+}
+
+/**
+Computes the Julian day of a date in the tabular Islamic calendar
+
+# Returns
+
+* `JD`: Julian day at 0h UT of the date. The Islamic day begins at
+        the preceding sunset.
+
+The tabular calendar has 30-day odd months and 29-day even months,
+with a 30th day added to the twelfth month in 11 leap years out of
+every 30. The actual calendar depends on sightings of the crescent
+Moon, and may differ from it by a day or two.
+
+# Arguments
+
+* `date`: An [IslamicDate](./struct.IslamicDate.html)
+**/
+pub fn julian_day_frm_islamic(date: &IslamicDate) -> Result<f64, Error> {
+
+    let days_in_month =
+        if date.month % 2 == 1 || (date.month == 12 && is_islamic_leap_year(date.year)) { 30 }
+        else                                                                              { 29 };
+    if date.month < 1 || date.month > 12 || date.day < 1 || date.day > days_in_month {
+        return Err(Error::InvalidDate);
+    }
+
+    Ok(islamic_JD(date.year as i64, date.month as i64, date.day as i64))
+
+}
+
+/**
+Computes the date in the tabular Islamic calendar of a Julian day
+
+# Returns
+
+* `date`: The [IslamicDate](./struct.IslamicDate.html) of the civil day
+          containing the Julian day. The Islamic day begins at the
+          preceding sunset.
+
+# Arguments
+
+* `JD`: Julian day
+**/
+pub fn islamic_frm_julian_day(JD: f64) -> IslamicDate {
+
+    let JD = (JD - 0.5).floor() + 0.5;
+
+    let year = ((30.0*(JD - ISLAMIC_EPOCH) + 10646.0) / 10631.0).floor() as i64;
+    let month = (((JD - islamic_JD(year, 1, 1) - 29.0) / 29.5).ceil() as i64 + 1).clamp(1, 12);
+    let day = (JD - islamic_JD(year, month, 1)) as i64 + 1;
+
+    IslamicDate { year: year as i16, month: month as u8, day: day as u8 }
+
+}
+
+/**
+Checks if a year of the tabular Islamic calendar is a leap year, with
+355 days
+
+# Arguments
+
+* `year`: Islamic year
+**/
+pub fn is_islamic_leap_year(year: i16) -> bool {
+
+    (14 + 11*(year as i32)).rem_euclid(30) < 11
+
+}
+
+// 1 Muharram 1 AH, July 16, 622 in the Julian calendar
+const ISLAMIC_EPOCH: f64 = 1948439.5;
+
+fn islamic_JD(year: i64, month: i64, day: i64) -> f64 {
+
+    ISLAMIC_EPOCH - 1.0
+  + (day + (29*(month - 1) + month/2) + 354*(year - 1) + (3 + 11*year).div_euclid(30)) as f64
+
+}
+
+fn month_frm_num(n: u8) -> Month {
+
+    match n {
+        1  => Month::Jan,
+        2  => Month::Feb,
+        3  => Month::Mar,
+        4  => Month::Apr,
+        5  => Month::May,
+        6  => Month::June,
+        7  => Month::July,
+        8  => Month::Aug,
+        9  => Month::Sept,
+        10 => Month::Oct,
+        11 => Month::Nov,
+        12 => Month::Dec,
+        _  => panic!("Internal error in time::calendar::month_frm_num")
+    }
+
+}
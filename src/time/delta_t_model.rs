@@ -0,0 +1,341 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Models of ΔT
+//!
+//! ΔT = TT - UT1 can only be known from observation, and different
+//! reconstructions of ancient eclipses rest on different conventions
+//! for it. A [Model](./enum.Model.html) selects one of them.
+
+use error::Error;
+use interpol;
+use std;
+use time::scale::LeapSecTable;
+
+/// Represents a model of ΔT
+#[derive(Clone, Debug)]
+pub enum Model {
+    /// The polynomial expressions of Espenak and Meeus, as in
+    /// [delta_t()](../fn.delta_t.html)
+    EspenakMeeus,
+    /// Tables of ΔT, in order of precedence, with the
+    /// [long-term parabola](./fn.long_term_parabola.html) joined
+    /// on to them for epochs outside all of the tables
+    Tabulated(Vec<Table>),
+    /// The [long-term parabola](./fn.long_term_parabola.html) alone
+    LongTermParabola,
+}
+
+/// Represents a table of ΔT at equal intervals of time
+#[derive(Clone, Debug)]
+pub struct Table {
+    start: f64,
+    step: f64,
+    values: Vec<f64>,
+}
+
+/// ΔT *| in seconds* from 1620 to 2024 in steps of 2 years, from
+/// *Meeus* and the IERS
+const DELTA_T: [f64; 203] = [
+    121.0, 112.0, 103.0,  95.0,  88.0,  82.0,  77.0,  72.0,  68.0,  63.0,
+     60.0,  56.0,  53.0,  51.0,  48.0,  46.0,  44.0,  42.0,  40.0,  38.0,
+     35.0,  33.0,  31.0,  29.0,  26.0,  24.0,  22.0,  20.0,  18.0,  16.0,
+     14.0,  12.0,  11.0,  10.0,   9.0,   8.0,   7.0,   7.0,   7.0,   7.0,
+      7.0,   7.0,   8.0,   8.0,   9.0,   9.0,   9.0,   9.0,   9.0,  10.0,
+     10.0,  10.0,  10.0,  10.0,  10.0,  10.0,  10.0,  11.0,  11.0,  11.0,
+     11.0,  11.0,  12.0,  12.0,  12.0,  12.0,  13.0,  13.0,  13.0,  14.0,
+     14.0,  14.0,  14.0,  15.0,  15.0,  15.0,  15.0,  15.0,  16.0,  16.0,
+     16.0,  16.0,  16.0,  16.0,  16.0,  16.0,  15.0,  15.0,  14.0,  13.0,
+     13.1,  12.5,  12.2,  12.0,  12.0,  12.0,  12.0,  12.0,  12.0,  11.9,
+     11.6,  11.0,  10.2,   9.2,   8.2,   7.1,   6.2,   5.6,   5.4,   5.3,
+      5.4,   5.6,   5.9,   6.2,   6.5,   6.8,   7.1,   7.3,   7.5,   7.6,
+      7.7,   7.3,   6.2,   5.2,   2.7,   1.4,  -1.2,  -2.8,  -3.8,  -4.8,
+     -5.5,  -5.3,  -5.6,  -5.7,  -5.9,  -6.0,  -6.3,  -6.5,  -6.2,  -4.7,
+     -2.8,  -0.1,   2.6,   5.3,   7.7,  10.4,  13.3,  16.0,  18.2,  20.2,
+     21.1,  22.4,  23.5,  23.8,  24.3,  24.0,  23.9,  23.9,  23.7,  24.0,
+     24.3,  25.3,  26.2,  27.3,  28.2,  29.1,  30.0,  30.7,  31.4,  32.2,
+     33.1,  34.0,  35.0,  36.5,  38.3,  40.2,  42.2,  44.5,  46.5,  48.5,
+     50.5,  52.2,  53.8,  54.9,  55.8,  56.9,  58.3,  60.0,  61.6,  63.0,
+     63.83, 64.30, 64.57, 64.85, 65.46, 66.07, 66.60, 67.28, 68.10, 68.97,
+     69.36, 69.29, 69.20,
+];
+
+impl Table {
+    /**
+    Creates a table of ΔT
+
+    # Arguments
+
+    * `start` : Decimal year of the first value
+    * `step`  : Interval between the values *| in years*
+    * `values`: ΔT *| in seconds*, at least three values
+    **/
+    pub fn new(start: f64, step: f64, values: Vec<f64>) -> Table {
+        assert!(values.len() >= 3 && step > 0.0);
+        Table { start, step, values }
+    }
+
+    /// Returns the table of ΔT from historical observations built
+    /// into this crate, from 1620 to 2024
+    pub fn builtin() -> Table {
+        Table::new(1620.0, 2.0, DELTA_T.to_vec())
+    }
+
+    /**
+    Parses a table of ΔT from the IERS file `finals.data` or
+    `finals2000A.data`
+
+    ΔT is computed from UT1 - UTC, both observed and predicted, as
+    `32.184 + (TAI - UTC) - (UT1 - UTC)`. Lines that have no UT1 - UTC
+    end the table.
+
+    # Arguments
+
+    * `text`     : Contents of the file
+    * `leap_secs`: Table of leap seconds covering the dates in `text`
+    **/
+    pub fn parse_finals(text: &str, leap_secs: &LeapSecTable) -> Result<Table, Error> {
+
+        let mut start_MJD = None;
+        let mut values = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let invalid = Error::InvalidTable { line: i + 1 };
+
+            let UT1_minus_UTC = match line.get(58..68).map(str::trim) {
+                Some(s) if !s.is_empty() => s.parse::<f64>().map_err(|_| invalid)?,
+                _ => break,
+            };
+            let MJD = line.get(7..15)
+                .and_then(|s| s.trim().parse::<f64>().ok())
+                .ok_or(invalid)?;
+
+            match start_MJD {
+                None => start_MJD = Some(MJD),
+                Some(start) =>
+                    if MJD != start + (values.len() as f64) {
+                        return Err(invalid);
+                    },
+            }
+
+            let JD = MJD + 2400000.5;
+            values.push(32.184 + leap_secs.TAI_minus_UTC(JD) - UT1_minus_UTC);
+        }
+
+        match start_MJD {
+            Some(start) if values.len() >= 3 => Ok(Table::new(
+                decimal_year(start + 2400000.5), 1.0/365.25, values
+            )),
+            _ => Err(Error::InvalidTable { line: values.len() + 1 }),
+        }
+
+    }
+
+    /**
+    Loads a table of ΔT from the IERS file `finals.data` or
+    `finals2000A.data`, as in [parse_finals()](#method.parse_finals)
+
+    # Arguments
+
+    * `path`     : Path of the file
+    * `leap_secs`: Table of leap seconds covering the dates in the file
+    **/
+    pub fn load_finals<P: AsRef<std::path::Path>>(
+        path      : P,
+        leap_secs : &LeapSecTable
+    ) -> Result<Table, Error> {
+
+        let text = std::fs::read_to_string(path)?;
+
+        Table::parse_finals(&text, leap_secs)
+
+    }
+
+    /// Returns the decimal year of the first value
+    #[inline]
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Returns the decimal year of the last value
+    #[inline]
+    pub fn end(&self) -> f64 {
+        self.start + self.step*((self.values.len() - 1) as f64)
+    }
+
+    /**
+    Interpolates ΔT from the table
+
+    # Returns
+
+    * `delta_t`: ΔT *| in seconds*, or `None` if `year` is outside
+                 the table
+
+    # Arguments
+
+    * `year`: Decimal year
+    **/
+    pub fn delta_t(&self, year: f64) -> Option<f64> {
+
+        if year < self.start || year > self.end() {
+            return None;
+        }
+
+        let n = (year - self.start) / self.step;
+        let i = (n.round() as usize).max(1).min(self.values.len() - 2);
+
+        Some(interpol::three_values(
+            self.values[i - 1], self.values[i], self.values[i + 1], n - (i as f64)
+        ))
+
+    }
+}
+
+impl Model {
+    /// Returns the model of ΔT tabulated from historical observations
+    /// built into this crate
+    pub fn tabulated() -> Model {
+        Model::Tabulated(vec![Table::builtin()])
+    }
+
+    /**
+    Computes ΔT
+
+    # Returns
+
+    * `delta_t`: ΔT *| in seconds*
+
+    # Arguments
+
+    * `year`: Decimal year
+    **/
+    pub fn delta_t(&self, year: f64) -> f64 {
+
+        match *self {
+            Model::EspenakMeeus => {
+                let whole_year = year.floor();
+                let month = ((year - whole_year)*12.0) as u8 + 1;
+                super::delta_t(whole_year as i32, month.min(12))
+            },
+            Model::Tabulated(ref tables) => {
+                if let Some(delta_t) = tables.iter().filter_map(|t| t.delta_t(year)).next() {
+                    return delta_t;
+                }
+
+                let start = tables.iter().min_by(|a, b| cmp(a.start(), b.start()));
+                let end = tables.iter().max_by(|a, b| cmp(a.end(), b.end()));
+
+                let joint = match (start, end) {
+                    (Some(start), _) if year < start.start() => start.start(),
+                    (_, Some(end)) => end.end(),
+                    _ => return long_term_parabola(year),
+                };
+                let offset = tables.iter()
+                    .filter_map(|t| t.delta_t(joint)).next().unwrap_or(0.0)
+                  - long_term_parabola(joint);
+
+                long_term_parabola(year) + offset
+            },
+            Model::LongTermParabola => long_term_parabola(year),
+        }
+
+    }
+
+    /**
+    Computes ΔT for a Julian day
+
+    # Returns
+
+    * `delta_t`: ΔT *| in seconds*
+
+    # Arguments
+
+    * `JD`: Julian day
+    **/
+    #[inline]
+    pub fn delta_t_frm_JD(&self, JD: f64) -> f64 {
+        self.delta_t(decimal_year(JD))
+    }
+}
+
+/**
+Computes ΔT from the long-term parabola of Morrison and Stephenson (2004)
+
+# Returns
+
+* `delta_t`: ΔT *| in seconds*
+
+ΔT = -20 + 32u², with u counted in centuries from 1820. The parabola
+assumes a secular acceleration of the Moon of -26 arcseconds per
+century²; see [lunar_accel_correction()](./fn.lunar_accel_correction.html).
+
+# Arguments
+
+* `year`: Decimal year
+**/
+#[inline]
+pub fn long_term_parabola(year: f64) -> f64 {
+
+    let u = (year - 1820.0) / 100.0;
+
+    32.0*u*u - 20.0
+
+}
+
+/**
+Computes the correction to ΔT for a different secular acceleration of
+the Moon
+
+# Returns
+
+* `correction`: Correction to add to ΔT *| in seconds*
+
+Values of ΔT deduced from ancient eclipses depend on the secular
+acceleration of the Moon assumed. The tables and the parabola here
+assume -26 arcseconds per century²; a lunar theory that uses a
+different value needs ΔT corrected by the value returned here.
+ELP-2000/82 uses -23.8946.
+
+# Arguments
+
+* `year` : Decimal year
+* `n_dot`: Secular acceleration of the Moon assumed by the lunar
+           theory *| in arcseconds per century²*
+**/
+#[inline]
+pub fn lunar_accel_correction(year: f64, n_dot: f64) -> f64 {
+
+    let t = year - 1955.0;
+
+    -0.000091 * (n_dot + 26.0) * t * t
+
+}
+
+#[inline]
+fn decimal_year(JD: f64) -> f64 {
+    2000.0 + (JD - 2451544.5)/365.25
+}
+
+#[inline]
+fn cmp(a: f64, b: f64) -> std::cmp::Ordering {
+    a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+}
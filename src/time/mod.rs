@@ -22,7 +22,12 @@ THE SOFTWARE.
 
 //! Time for astronomy
 
+pub mod calendar;
+pub mod delta_t_model;
+pub mod scale;
+
 use angle;
+use error::Error;
 
 /// Represents a calendar type
 #[derive(Debug)]
@@ -34,7 +39,7 @@ pub enum CalType {
 }
 
 /// Represents a month in the Gregorian and Julian calendars
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Month {
     /// January
     Jan = 1,
@@ -274,6 +279,9 @@ pub fn julian_day(date: &Date) -> f64 {
 /**
 Computes the Julian Ephemeris day
 
+See [scale](./scale/index.html) for conversions between UTC, TAI, TT,
+TDB and UT1 that take leap seconds into account.
+
 # Arguments
 
 * `JD`     : Julian day
@@ -299,12 +307,14 @@ Computes a year, month and decimal day equivalent to a given Julian day
 
 # Arguments
 
-`JD`: Julian Day. **Can't be a negative value.**
+`JD`: Julian Day. **Can't be a negative value**, an
+      [Error::JulianDayOutOfRange](../error/enum.Error.html) is
+      returned for one.
 **/
-pub fn date_frm_julian_day<'a> (mut JD: f64) -> Result<(i16, u8, f64), &'a str> {
+pub fn date_frm_julian_day(mut JD: f64) -> Result<(i16, u8, f64), Error> {
 
     if JD < 0.0 {
-        return Err("A negative value for JD was passed to time::date_frm_julian_day()");
+        return Err(Error::JulianDayOutOfRange(JD));
     }
 
     JD += 0.5;
@@ -328,13 +338,13 @@ pub fn date_frm_julian_day<'a> (mut JD: f64) -> Result<(i16, u8, f64), &'a str>
     let month =      if E < 14             { E - 1  }
                 else if E == 14 || E == 15 { E - 13 }
                 else {
-                    return Err("Internal error in time::date_frm_julian_day()");
+                    return Err(Error::InvalidDate);
                 };
 
     let year =      if month > 2                { C - 4716 }
                else if month == 1 || month == 2 { C - 4715 }
                else {
-                   return Err("Internal error in time::date_frm_julian_day()");
+                   return Err(Error::InvalidDate);
                };
 
     Ok( (year as i16, month as u8, day) )
@@ -414,7 +424,8 @@ Computes an approximate value of ΔT for a given year and month
 This function approximates ΔT from polynomial expressions using a
 method different from that given in the *Meeus* book. The method
 used is given [here](http://eclipse.gsfc.nasa.gov/SEcat5/deltatpoly.html);
-it covers a far wider time range, and is more accurate.
+it covers a far wider time range, and is more accurate. For ΔT
+tabulated from observations, see [delta_t_model](./delta_t_model/index.html).
 
 # Arguments
 
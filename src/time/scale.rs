@@ -0,0 +1,361 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Time scales
+//!
+//! An [Instant](./struct.Instant.html) is a Julian day tagged with the
+//! [TimeScale](./enum.TimeScale.html) it is reckoned in, and can be
+//! converted to any other scale. Civil time is kept in UTC, the
+//! VSOP87 and ELP-2000/82 theories take TDB (for which TT can stand
+//! in to within 2 milliseconds), and sidereal time and the rotation
+//! of the Earth follow UT1.
+
+use time;
+use time::delta_t_model;
+
+/// Represents a time scale
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TimeScale {
+    /// Coordinated Universal Time, the scale of civil clocks
+    UTC,
+    /// International Atomic Time
+    TAI,
+    /// Terrestrial Time, formerly Ephemeris Time
+    TT,
+    /// Barycentric Dynamical Time
+    TDB,
+    /// Universal Time, following the rotation of the Earth
+    UT1,
+}
+
+/// Represents an instant of time
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Instant {
+    /// Julian day in the time scale `scale`
+    pub JD: f64,
+    /// Time scale of `JD`
+    pub scale: TimeScale,
+}
+
+/// Represents an entry in a table of leap seconds
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LeapSec {
+    /// Julian day in UTC from which the entry holds
+    pub JD: f64,
+    /// TAI - UTC on `JD` *| in seconds*
+    pub TAI_minus_UTC: f64,
+    /// Modified Julian day from which `drift` is reckoned
+    pub MJD_ref: f64,
+    /// Daily drift of TAI - UTC *| in seconds*. Non-zero only
+    /// for the rubber seconds of UTC before 1972.
+    pub drift: f64,
+}
+
+/// Represents how UT1 is obtained from the uniform time scales
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum UT1Model {
+    /// ΔT = TT - UT1 from [delta_t()](../fn.delta_t.html)
+    ApproxDeltaT,
+    /// A given value of ΔT = TT - UT1 *| in seconds*
+    DeltaT(f64),
+    /// A given value of DUT1 = UT1 - UTC *| in seconds*, as published
+    /// by the IERS
+    DUT1(f64),
+}
+
+/// Represents a table of leap seconds, that can be updated as the
+/// IERS announces new leap seconds
+#[derive(Clone, Debug)]
+pub struct LeapSecTable {
+    entries: Vec<LeapSec>,
+}
+
+/// Represents the data needed to convert between time scales
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Table of leap seconds to convert to and from UTC
+    pub leap_secs: LeapSecTable,
+    /// How UT1 is obtained
+    pub ut1: UT1Model,
+}
+
+/// TT - TAI *| in seconds*
+const TT_MINUS_TAI: f64 = 32.184;
+
+macro_rules! leap_sec {
+    ($JD: expr, $TAI_minus_UTC: expr) => {
+        leap_sec!($JD, $TAI_minus_UTC, 0.0, 0.0)
+    };
+    ($JD: expr, $TAI_minus_UTC: expr, $MJD_ref: expr, $drift: expr) => {
+        LeapSec {
+            JD: $JD, TAI_minus_UTC: $TAI_minus_UTC, MJD_ref: $MJD_ref, drift: $drift
+        }
+    };
+}
+
+/// Leap seconds up to the one at the end of 2016, preceded by the
+/// drifting offsets of UTC from 1961 to 1972
+const LEAP_SECS: [LeapSec; 41] = [
+    leap_sec!(2437300.5,  1.4228180, 37300.0, 0.001296),
+    leap_sec!(2437512.5,  1.3728180, 37300.0, 0.001296),
+    leap_sec!(2437665.5,  1.8458580, 37665.0, 0.0011232),
+    leap_sec!(2438334.5,  1.9458580, 37665.0, 0.0011232),
+    leap_sec!(2438395.5,  3.2401300, 38761.0, 0.001296),
+    leap_sec!(2438486.5,  3.3401300, 38761.0, 0.001296),
+    leap_sec!(2438639.5,  3.4401300, 38761.0, 0.001296),
+    leap_sec!(2438761.5,  3.5401300, 38761.0, 0.001296),
+    leap_sec!(2438820.5,  3.6401300, 38761.0, 0.001296),
+    leap_sec!(2438942.5,  3.7401300, 38761.0, 0.001296),
+    leap_sec!(2439004.5,  3.8401300, 38761.0, 0.001296),
+    leap_sec!(2439126.5,  4.3131700, 39126.0, 0.002592),
+    leap_sec!(2439887.5,  4.2131700, 39126.0, 0.002592),
+    leap_sec!(2441317.5, 10.0),
+    leap_sec!(2441499.5, 11.0),
+    leap_sec!(2441683.5, 12.0),
+    leap_sec!(2442048.5, 13.0),
+    leap_sec!(2442413.5, 14.0),
+    leap_sec!(2442778.5, 15.0),
+    leap_sec!(2443144.5, 16.0),
+    leap_sec!(2443509.5, 17.0),
+    leap_sec!(2443874.5, 18.0),
+    leap_sec!(2444239.5, 19.0),
+    leap_sec!(2444786.5, 20.0),
+    leap_sec!(2445151.5, 21.0),
+    leap_sec!(2445516.5, 22.0),
+    leap_sec!(2446247.5, 23.0),
+    leap_sec!(2447161.5, 24.0),
+    leap_sec!(2447892.5, 25.0),
+    leap_sec!(2448257.5, 26.0),
+    leap_sec!(2448804.5, 27.0),
+    leap_sec!(2449169.5, 28.0),
+    leap_sec!(2449534.5, 29.0),
+    leap_sec!(2450083.5, 30.0),
+    leap_sec!(2450630.5, 31.0),
+    leap_sec!(2451179.5, 32.0),
+    leap_sec!(2453736.5, 33.0),
+    leap_sec!(2454832.5, 34.0),
+    leap_sec!(2456109.5, 35.0),
+    leap_sec!(2457204.5, 36.0),
+    leap_sec!(2457754.5, 37.0),
+];
+
+impl LeapSecTable {
+    /// Returns the table of leap seconds built into this crate
+    pub fn builtin() -> LeapSecTable {
+        LeapSecTable { entries: LEAP_SECS.to_vec() }
+    }
+
+    /**
+    Inserts a leap second into the table
+
+    # Arguments
+
+    * `JD`           : Julian day in UTC from which the new offset holds
+                       (the 0h UTC following the leap second)
+    * `TAI_minus_UTC`: TAI - UTC from `JD` onwards *| in seconds*
+    **/
+    pub fn insert(&mut self, JD: f64, TAI_minus_UTC: f64) {
+        self.entries.retain(|e| e.JD != JD);
+        let i = self.entries.iter().position(|e| e.JD > JD).unwrap_or(self.entries.len());
+        self.entries.insert(i, leap_sec!(JD, TAI_minus_UTC));
+    }
+
+    /// Returns the entries of the table, in order of time
+    pub fn entries(&self) -> &[LeapSec] {
+        &self.entries
+    }
+
+    /**
+    Computes TAI - UTC
+
+    # Returns
+
+    * `TAI_minus_UTC`: TAI - UTC *| in seconds*
+
+    Before 1961, when UTC did not exist, UTC is taken to be UT1 as
+    given by [delta_t()](../fn.delta_t.html).
+
+    # Arguments
+
+    * `JD`: Julian day in UTC
+    **/
+    pub fn TAI_minus_UTC(&self, JD: f64) -> f64 {
+        TAI_minus_UTC(&self.entries, JD)
+    }
+}
+
+impl Default for LeapSecTable {
+    fn default() -> LeapSecTable {
+        LeapSecTable::builtin()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            leap_secs: LeapSecTable::builtin(),
+            ut1: UT1Model::ApproxDeltaT
+        }
+    }
+}
+
+impl Instant {
+    /// Creates an instant from a Julian day in a time scale
+    #[inline]
+    pub fn new(JD: f64, scale: TimeScale) -> Instant {
+        Instant { JD, scale }
+    }
+
+    /// Creates an instant from a date in a time scale
+    #[inline]
+    pub fn frm_date(date: &time::Date, scale: TimeScale) -> Instant {
+        Instant::new(time::julian_day(date), scale)
+    }
+
+    /**
+    Converts the instant to another time scale, using the built-in
+    table of leap seconds and ΔT from [delta_t()](../fn.delta_t.html)
+
+    # Arguments
+
+    * `scale`: The [TimeScale](./enum.TimeScale.html) to convert to
+    **/
+    pub fn to(&self, scale: TimeScale) -> Instant {
+        self.convert(scale, &LEAP_SECS, &UT1Model::ApproxDeltaT)
+    }
+
+    /**
+    Converts the instant to another time scale
+
+    # Arguments
+
+    * `scale` : The [TimeScale](./enum.TimeScale.html) to convert to
+    * `config`: The [Config](./struct.Config.html) of leap seconds and UT1
+    **/
+    pub fn to_with(&self, scale: TimeScale, config: &Config) -> Instant {
+        self.convert(scale, &config.leap_secs.entries, &config.ut1)
+    }
+
+    /// Returns the Julian day of the instant in a time scale, converted
+    /// as by [to()](#method.to)
+    #[inline]
+    pub fn JD_in(&self, scale: TimeScale) -> f64 {
+        self.to(scale).JD
+    }
+
+    fn convert(&self, scale: TimeScale, leap_secs: &[LeapSec], ut1: &UT1Model) -> Instant {
+        if scale == self.scale {
+            return *self;
+        }
+
+        let TT = to_TT(self.JD, self.scale, leap_secs, ut1);
+
+        Instant::new(frm_TT(TT, scale, leap_secs, ut1), scale)
+    }
+}
+
+/**
+Computes TDB - TT
+
+# Returns
+
+* `TDB_minus_TT`: TDB - TT *| in seconds*
+
+This function evaluates the leading periodic terms of the series of
+Fairhead and Bretagnon (1990), as adopted in the IERS Conventions.
+The result agrees with the full series to within 10 microseconds
+between the years 1600 and 2200.
+
+# Arguments
+
+* `JD`: Julian day in TT
+**/
+pub fn TDB_minus_TT(JD: f64) -> f64 {
+
+    let T = time::julian_cent(JD);
+
+      0.001657   * (628.3076*T + 6.2401).sin()
+    + 0.000022   * (575.3385*T + 4.2970).sin()
+    + 0.000014   * (1256.6152*T + 6.1969).sin()
+    + 0.000005   * (606.9777*T + 4.0212).sin()
+    + 0.000005   * (52.9691*T + 0.4444).sin()
+    + 0.000002   * (21.3299*T + 5.5431).sin()
+    + 0.00001*T  * (628.3076*T + 4.2490).sin()
+
+}
+
+fn TAI_minus_UTC(leap_secs: &[LeapSec], JD: f64) -> f64 {
+
+    match leap_secs.iter().rev().find(|e| e.JD <= JD) {
+        Some(e) => e.TAI_minus_UTC + (JD - 2400000.5 - e.MJD_ref)*e.drift,
+        None    => approx_delta_t(JD) - TT_MINUS_TAI,
+    }
+
+}
+
+#[inline]
+fn approx_delta_t(JD: f64) -> f64 {
+
+    delta_t_model::Model::EspenakMeeus.delta_t_frm_JD(JD)
+
+}
+
+fn to_TT(JD: f64, scale: TimeScale, leap_secs: &[LeapSec], ut1: &UT1Model) -> f64 {
+
+    match scale {
+        TimeScale::TT  => JD,
+        TimeScale::TAI => JD + TT_MINUS_TAI/86400.0,
+        TimeScale::UTC => JD + (TAI_minus_UTC(leap_secs, JD) + TT_MINUS_TAI)/86400.0,
+        TimeScale::TDB => JD - TDB_minus_TT(JD)/86400.0,
+        TimeScale::UT1 => match *ut1 {
+            UT1Model::ApproxDeltaT  => JD + approx_delta_t(JD)/86400.0,
+            UT1Model::DeltaT(delta_t) => JD + delta_t/86400.0,
+            UT1Model::DUT1(DUT1) =>
+                to_TT(JD - DUT1/86400.0, TimeScale::UTC, leap_secs, ut1),
+        },
+    }
+
+}
+
+fn frm_TT(TT: f64, scale: TimeScale, leap_secs: &[LeapSec], ut1: &UT1Model) -> f64 {
+
+    match scale {
+        TimeScale::TT  => TT,
+        TimeScale::TAI => TT - TT_MINUS_TAI/86400.0,
+        TimeScale::UTC => {
+            let TAI = TT - TT_MINUS_TAI/86400.0;
+            let UTC = TAI - TAI_minus_UTC(leap_secs, TAI)/86400.0;
+            TAI - TAI_minus_UTC(leap_secs, UTC)/86400.0
+        },
+        TimeScale::TDB => TT + TDB_minus_TT(TT)/86400.0,
+        TimeScale::UT1 => match *ut1 {
+            UT1Model::ApproxDeltaT => {
+                let UT1 = TT - approx_delta_t(TT)/86400.0;
+                TT - approx_delta_t(UT1)/86400.0
+            },
+            UT1Model::DeltaT(delta_t) => TT - delta_t/86400.0,
+            UT1Model::DUT1(DUT1) =>
+                frm_TT(TT, TimeScale::UTC, leap_secs, ut1) + DUT1/86400.0,
+        },
+    }
+
+}
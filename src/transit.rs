@@ -23,8 +23,18 @@ THE SOFTWARE.
 //! Time of rise, transit and set for a celestial body
 
 use angle;
+use angle::Radians;
 use coords;
+use ecliptic;
 use interpol;
+use lunar;
+use nutation;
+use planet;
+use search;
+use star;
+use std;
+use sun;
+use time;
 
 /// Represents a celestial body in transit
 pub enum TransitBody {
@@ -120,15 +130,15 @@ pub fn time (
     };
 
     let mut H = coords::hr_angl_frm_observer_long
-        (theta0, geograph_point.long, asc).to_degrees();
+        (Radians(theta0), Radians(geograph_point.long), Radians(asc)).to_degrees();
     H = angle::limit_to_360(H);
     if H > 180.0 { H -= 360.0; }
     H = H.to_radians();
 
     let h = match transit_type {
         &TransitType::Transit => 0.0,
-        &TransitType::Rise    => coords::alt_frm_eq(H, dec, geograph_point.lat),
-        &TransitType::Set     => coords::alt_frm_eq(H, dec, geograph_point.lat)
+        &TransitType::Rise    => coords::alt_frm_eq(Radians(H), Radians(dec), Radians(geograph_point.lat)),
+        &TransitType::Set     => coords::alt_frm_eq(Radians(H), Radians(dec), Radians(geograph_point.lat))
     };
 
     m += match transit_type {
@@ -173,3 +183,437 @@ fn m (
     m
 
 }
+
+/// Represents a celestial body, as taken by
+/// [rise_transit_set()](./fn.rise_transit_set.html) and
+/// [conjunct::conjunctions()](../conjunct/fn.conjunctions.html)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Body {
+    /// The Sun
+    Sun,
+    /// The Moon
+    Moon,
+    /// A planet other than the Earth
+    Planet(planet::Planet),
+    /// A fixed [Star](../star/struct.Star.html)
+    Star(star::Star),
+}
+
+/// Holds the times of rise, transit and set of a celestial body
+///
+/// *All times are Julian days, in UT*
+#[derive(Debug)]
+pub enum RiseTransitSet {
+    /// The body crosses the altitude threshold twice during the day
+    RisesAndSets {
+        /// Rise
+        rise: f64,
+        /// Transit across the meridian
+        transit: f64,
+        /// Set
+        set: f64,
+    },
+    /// The body stays above the altitude threshold all day
+    Circumpolar {
+        /// Transit across the meridian
+        transit: f64,
+    },
+    /// The body stays below the altitude threshold all day
+    NeverRises {
+        /// Transit across the meridian
+        transit: f64,
+    },
+}
+
+/**
+Computes the standard altitude of a celestial body, i.e, the
+geometric altitude of its center at the time of apparent rising
+or setting
+
+# Returns
+
+* `h0`: Standard altitude *| in radians*
+
+The values account for atmospheric refraction, and for the Sun
+and Moon, their semidiameters. For the Moon, the parallax is also
+accounted for.
+
+# Arguments
+
+* `body`: The [Body](./enum.Body.html)
+* `JD`  : Julian (Ephemeris) day
+**/
+pub fn std_alt(body: &Body, JD: f64) -> f64 {
+
+    match *body {
+        Body::Sun  => -0.8333_f64.to_radians(),
+        Body::Moon => {
+            let (_, moon_earth_dist) = lunar::geocent_ecl_pos(JD);
+            0.7275*lunar::eq_hz_parllx(moon_earth_dist) - 0.5667_f64.to_radians()
+        },
+        Body::Planet(_) | Body::Star(_) => -0.5667_f64.to_radians(),
+    }
+
+}
+
+/**
+Computes the times of rise, transit and set of a celestial body on
+a given day
+
+# Returns
+
+* `rise_transit_set`: The [RiseTransitSet](./enum.RiseTransitSet.html)
+
+Unlike [time()](./fn.time.html), the position of the body is computed
+here at each step of an iteration that is continued until the times
+converge to within a tenth of a second. A body that does not cross the
+altitude `h0` on the day is reported as circumpolar or as never rising,
+based on its declination at transit.
+
+# Arguments
+
+* `body`          : The [Body](./enum.Body.html)
+* `geograph_point`: Geographic point of the observer *| in radians*,
+                    with longitude measured positively westwards
+* `date`          : The day of interest, in UT. *Only the integral part
+                    of* `date.decimal_day` *is used*.
+* `h0`            : Altitude of the body's center to be crossed
+                    *| in radians*. Pass the value returned by
+                    [std_alt()](./fn.std_alt.html) for the usual
+                    rise and set.
+* `delta_t`       : ΔT for the day
+**/
+pub fn rise_transit_set (
+
+    body           : &Body,
+    geograph_point : &coords::GeographPoint,
+    date           : &time::Date,
+    h0             : f64,
+    delta_t        : f64
+
+) -> RiseTransitSet {
+
+    let date_0UT = time::Date {
+        year        : date.year,
+        month       : date.month,
+        decimal_day : date.decimal_day.floor(),
+        cal_type    : match date.cal_type {
+            time::CalType::Gregorian => time::CalType::Gregorian,
+            time::CalType::Julian    => time::CalType::Julian,
+        }
+    };
+    let JD0 = time::julian_day(&date_0UT);
+    let Theta0 = apprnt_sidr(JD0 + delta_t/86400.0, JD0);
+
+    let eq_point_at = |m: f64| apprnt_eq_coords(body, JD0 + m + delta_t/86400.0);
+    let loc_hr_angl = |m: f64, asc: f64| {
+        let theta = Theta0 + 360.985647_f64.to_radians()*m;
+        let mut H = angle::limit_to_two_PI(
+            coords::hr_angl_frm_observer_long(Radians(theta), Radians(geograph_point.long), Radians(asc))
+        );
+        if H > std::f64::consts::PI { H -= angle::TWO_PI; }
+        H
+    };
+
+    let eq_point = eq_point_at(0.5);
+    let mut transit = limit_to_day(
+        (eq_point.asc + geograph_point.long - Theta0) / angle::TWO_PI
+    );
+    for _ in 0..10 {
+        let dm = -loc_hr_angl(transit, eq_point_at(transit).asc) / angle::TWO_PI;
+        transit += dm;
+        if dm.abs() < 0.000001 { break; }
+    }
+
+    let dec = eq_point_at(transit).dec;
+    let cos_H0 =
+        (h0.sin() - geograph_point.lat.sin()*dec.sin())
+      / (geograph_point.lat.cos() * dec.cos());
+
+    if cos_H0 < -1.0 {
+        return RiseTransitSet::Circumpolar { transit: JD0 + transit };
+    }
+    if cos_H0 > 1.0 {
+        return RiseTransitSet::NeverRises { transit: JD0 + transit };
+    }
+
+    let H0 = cos_H0.acos();
+    let crossing = |mut m: f64| {
+        for _ in 0..10 {
+            let eq_point = eq_point_at(m);
+            let H = loc_hr_angl(m, eq_point.asc);
+            let h = coords::alt_frm_eq(Radians(H), Radians(eq_point.dec), Radians(geograph_point.lat));
+            let dm =
+                (h - h0)
+              / (angle::TWO_PI * eq_point.dec.cos() * geograph_point.lat.cos() * H.sin());
+            m += dm;
+            if dm.abs() < 0.000001 { break; }
+        }
+        JD0 + m
+    };
+
+    RiseTransitSet::RisesAndSets {
+        rise    : crossing(limit_to_day(transit - H0/angle::TWO_PI)),
+        transit : JD0 + transit,
+        set     : crossing(limit_to_day(transit + H0/angle::TWO_PI))
+    }
+
+}
+
+/// Represents a kind of twilight
+#[derive(Copy, Clone, Debug)]
+pub enum Twilight {
+    /// Civil twilight, when the Sun's center is 6 degrees below
+    /// the horizon
+    Civil,
+    /// Nautical twilight, when the Sun's center is 12 degrees below
+    /// the horizon
+    Nautical,
+    /// Astronomical twilight, when the Sun's center is 18 degrees below
+    /// the horizon
+    Astronomical,
+    /// Twilight at a custom depression of the Sun's center below the
+    /// horizon *| in radians*
+    Custom(f64),
+}
+
+impl Twilight {
+    /// Returns the depression of the Sun's center below the horizon
+    /// *| in radians*
+    pub fn depression(&self) -> f64 {
+        match *self {
+            Twilight::Civil              =>  6_f64.to_radians(),
+            Twilight::Nautical           => 12_f64.to_radians(),
+            Twilight::Astronomical       => 18_f64.to_radians(),
+            Twilight::Custom(depression) => depression,
+        }
+    }
+}
+
+/// Holds the times of dawn and dusk for a kind of twilight
+///
+/// *All times are Julian days, in UT*
+#[derive(Debug)]
+pub enum TwilightTimes {
+    /// The Sun's center crosses the depression twice during the day
+    DawnAndDusk {
+        /// Dawn, when the Sun rises above the depression
+        dawn: f64,
+        /// Dusk, when the Sun sinks below the depression
+        dusk: f64,
+    },
+    /// The Sun stays above the depression all day, as on a polar
+    /// day or a white night
+    AlwaysAbove,
+    /// The Sun stays below the depression all day, as on a polar
+    /// night
+    AlwaysBelow,
+}
+
+/**
+Computes the times of dawn and dusk for a kind of twilight on a
+given day
+
+# Returns
+
+* `twilight_times`: The [TwilightTimes](./enum.TwilightTimes.html)
+
+# Arguments
+
+* `twilight`      : The [Twilight](./enum.Twilight.html)
+* `geograph_point`: Geographic point of the observer *| in radians*,
+                    with longitude measured positively westwards
+* `date`          : The day of interest, in UT. *Only the integral part
+                    of* `date.decimal_day` *is used*.
+* `delta_t`       : ΔT for the day
+**/
+pub fn twilight (
+
+    twilight       : &Twilight,
+    geograph_point : &coords::GeographPoint,
+    date           : &time::Date,
+    delta_t        : f64
+
+) -> TwilightTimes {
+
+    match rise_transit_set(
+        &Body::Sun, geograph_point, date, -twilight.depression(), delta_t
+    ) {
+        RiseTransitSet::RisesAndSets { rise, set, .. } => TwilightTimes::DawnAndDusk {
+            dawn: rise,
+            dusk: set
+        },
+        RiseTransitSet::Circumpolar { .. } => TwilightTimes::AlwaysAbove,
+        RiseTransitSet::NeverRises { .. }  => TwilightTimes::AlwaysBelow,
+    }
+
+}
+
+/// Holds the period of darkness in a night
+///
+/// *All times are Julian days, in UT*
+#[derive(Debug)]
+pub enum Darkness {
+    /// The Sun sinks below the depression during the night and rises
+    /// above it again before the next day's transit
+    BeginsAndEnds {
+        /// Beginning of darkness
+        begin: f64,
+        /// End of darkness
+        end: f64,
+    },
+    /// The Sun sinks below the depression during the night, but no
+    /// rise above it is found before the next day's transit
+    NoEnd {
+        /// Beginning of darkness
+        begin: f64,
+    },
+    /// The Sun stays above the depression during the night
+    NoDarkness,
+}
+
+/**
+Computes the period of darkness in the night following a given day
+
+# Returns
+
+* `darkness`: The [Darkness](./enum.Darkness.html) between the Sun's
+              transit on `date` and its transit on the next day
+
+When the Sun stays below the depression all day, darkness is taken to
+begin or end at its transit. On the nights next to a polar day or a
+white night, when the Sun doesn't cross the depression on one of the
+two days but does during the night, the crossing is found by
+bisecting the Sun's altitude about its lower culmination.
+
+# Arguments
+
+* `twilight`      : The [Twilight](./enum.Twilight.html)
+* `geograph_point`: Geographic point of the observer *| in radians*,
+                    with longitude measured positively westwards
+* `date`          : The day of interest, in UT. *Only the integral part
+                    of* `date.decimal_day` *is used*.
+* `delta_t`       : ΔT for the night
+**/
+pub fn darkness (
+
+    twilight       : &Twilight,
+    geograph_point : &coords::GeographPoint,
+    date           : &time::Date,
+    delta_t        : f64
+
+) -> Darkness {
+
+    let next_date = time::Date {
+        year        : date.year,
+        month       : date.month,
+        decimal_day : date.decimal_day.floor() + 1.0,
+        cal_type    : match date.cal_type {
+            time::CalType::Gregorian => time::CalType::Gregorian,
+            time::CalType::Julian    => time::CalType::Julian,
+        }
+    };
+    let h0 = -twilight.depression();
+
+    let today = rise_transit_set(&Body::Sun, geograph_point, date, h0, delta_t);
+    let tomorrow = rise_transit_set(&Body::Sun, geograph_point, &next_date, h0, delta_t);
+
+    let (transit, next_transit) = (transit_of(&today), transit_of(&tomorrow));
+    let alt_minus_h0 = |JD: f64| sun_alt(geograph_point, JD, delta_t) - h0;
+    let lower_culmination = || search::golden_section_min(alt_minus_h0, transit, next_transit);
+
+    let begin = match today {
+        RiseTransitSet::RisesAndSets { set, .. } => set,
+        RiseTransitSet::NeverRises { transit }   => transit,
+        RiseTransitSet::Circumpolar { .. }       => {
+            match search::bisect(&alt_minus_h0, transit, lower_culmination()) {
+                Some(begin) => begin,
+                None        => return Darkness::NoDarkness,
+            }
+        },
+    };
+
+    let end = match tomorrow {
+        RiseTransitSet::RisesAndSets { rise, .. } => {
+            if rise > begin { rise } else { return Darkness::NoDarkness; }
+        },
+        RiseTransitSet::NeverRises { transit: next_transit } => next_transit,
+        RiseTransitSet::Circumpolar { .. }                   => {
+            let JD_min = lower_culmination().max(begin);
+            match search::bisect(&alt_minus_h0, JD_min, next_transit) {
+                Some(end) => end,
+                None      => return Darkness::NoEnd { begin },
+            }
+        },
+    };
+
+    Darkness::BeginsAndEnds { begin, end }
+
+}
+
+fn transit_of(rise_transit_set: &RiseTransitSet) -> f64 {
+
+    match *rise_transit_set {
+        RiseTransitSet::RisesAndSets { transit, .. } => transit,
+        RiseTransitSet::Circumpolar { transit }      => transit,
+        RiseTransitSet::NeverRises { transit }       => transit,
+    }
+
+}
+
+/**
+Computes the apparent geocentric place of a celestial body, referred
+to the true equator and equinox of the date
+
+# Returns
+
+* `eq_point`: Equatorial point of the body *| in radians*
+
+The Sun and the planets are corrected for light-time, annual aberration
+and nutation, the Moon for nutation, and stars as in
+[star::apprnt_eq_coords()](../star/fn.apprnt_eq_coords.html).
+
+# Arguments
+
+* `body`: The [Body](./enum.Body.html)
+* `JD`  : Julian (Ephemeris) day
+**/
+pub fn apprnt_eq_coords(body: &Body, JD: f64) -> coords::EqPoint {
+
+    match *body {
+        Body::Sun                => sun::apprnt_eq_pos(JD).0,
+        Body::Moon               => lunar::apprnt_eq_pos(JD).0,
+        Body::Planet(ref planet) => planet::geocent_apprnt_eq_coords(planet, JD).0,
+        Body::Star(ref star)     => star::apprnt_eq_coords(star, JD),
+    }
+
+}
+
+/// Computes the Sun's geometric altitude at a Julian day in UT
+fn sun_alt(geograph_point: &coords::GeographPoint, JD: f64, delta_t: f64) -> f64 {
+
+    let JD_ephm = JD + delta_t/86400.0;
+    let eq_point = apprnt_eq_coords(&Body::Sun, JD_ephm);
+    let H = coords::hr_angl_frm_observer_long(
+        Radians(apprnt_sidr(JD_ephm, JD)), Radians(geograph_point.long), Radians(eq_point.asc)
+    );
+
+    coords::alt_frm_eq(Radians(H), Radians(eq_point.dec), Radians(geograph_point.lat))
+
+}
+
+#[inline]
+fn limit_to_day(m: f64) -> f64 {
+
+    m - m.floor()
+
+}
+
+fn apprnt_sidr(JD: f64, JD_UT: f64) -> f64 {
+
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    time::apprnt_sidr(time::mn_sidr(JD_UT), nut_in_long, true_oblq)
+
+}
@@ -47,3 +47,32 @@ fn stell_aberr_in_eq_coords() {
     assert_eq!(util::round_upto_digits(b.to_degrees(), 7), 0.0018749);
 
 }
+
+#[test]
+fn stell_aberr_frm_earth_vel() {
+
+    // The same star as in stell_aberr_in_eq_coords()
+
+    let d = time::Date {
+        year        : 2028,
+        month       : time::Month::Nov,
+        decimal_day : 13.19,
+        cal_type    : time::CalType::Gregorian
+    };
+    let stell_eq_point = coords::EqPoint{
+        asc: 41.0540613_f64.to_radians(),
+        dec: 49.2277489_f64.to_radians()
+    };
+
+    let (a, b) = aberr::stell_aberr_frm_earth_vel (
+        &stell_eq_point, time::julian_day(&d)
+    );
+
+    // The star's coordinates are those of the date, 29 years from J2000.0,
+    // so the velocity turned to the equator of the date moves the result
+    // by up to 0.07″ from that of stell_aberr_in_eq_coords(), whose series
+    // are referred to J2000.0
+    assert_eq!(util::round_upto_digits(a.to_degrees(), 6), 0.008314);
+    assert_eq!(util::round_upto_digits(b.to_degrees(), 6), 0.001895);
+
+}
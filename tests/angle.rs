@@ -0,0 +1,89 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+use astro::*;
+use astro::angle::{Angle, Degrees, HourAngle, Radians};
+
+#[test]
+fn conversions() {
+
+    let asc = HourAngle::from_hms(23, 9, 16.641);
+    assert_eq!(util::round_upto_digits(asc.deg(), 5), 347.31934);
+
+    let deg = Degrees::from(asc);
+    assert_eq!(util::round_upto_digits(deg.0, 5), 347.31934);
+
+    let rad = Radians::from(Degrees(180.0));
+    assert_eq!(rad.0, std::f64::consts::PI);
+
+    assert_eq!(Degrees(-30.0).limit_to_360(), Degrees(330.0));
+    assert_eq!(HourAngle(25.5).limit_to_24(), HourAngle(1.5));
+    assert_eq!(
+        util::round_upto_digits(Radians(-1.0).limit_to_two_PI().0, 6),
+        util::round_upto_digits(angle::TWO_PI - 1.0, 6)
+    );
+
+    // Typed angles may be passed wherever radians are expected
+    let (asc, dec, oblq_eclip) = (
+        HourAngle::from_hms(7, 45, 18.946),
+        Degrees::from_dms(28, 1, 34.26),
+        Degrees(23.4392911)
+    );
+    assert_eq!(
+        coords::ecl_long_frm_eq(asc, dec, oblq_eclip),
+        coords::ecl_long_frm_eq(asc.rad(), dec.rad(), oblq_eclip.rad())
+    );
+
+    let point = coords::EqPoint::new(asc, dec);
+    assert_eq!(point.asc, asc.rad());
+    assert_eq!(point.dec, dec.rad());
+
+}
+
+#[test]
+fn sexagesimal() {
+
+    assert_eq!(format!("{}", Degrees::from_dms(13, 46, 8.0)), "13°46'8.00\"");
+    assert_eq!(format!("{:.1}", Degrees(-0.5)), "-0°30'0.0\"");
+    assert_eq!(format!("{:.0}", Degrees(9.99999)), "10°0'0\"");
+    assert_eq!(format!("{}", HourAngle::from_hms(2, 44, 12.9747)), "2h44m12.97s");
+
+    let d: Degrees = "-0°30'36\"".parse().unwrap();
+    assert_eq!(d, Degrees(-0.51));
+    let d: Degrees = "28 1 34.26".parse().unwrap();
+    assert_eq!(d, Degrees::from_dms(28, 1, 34.26));
+    let d: Degrees = "12.5".parse().unwrap();
+    assert_eq!(d, Degrees(12.5));
+
+    let h: HourAngle = "7h45m18.946s".parse().unwrap();
+    assert_eq!(h, HourAngle::from_hms(7, 45, 18.946));
+    let h: HourAngle = "07:45:18.946".parse().unwrap();
+    assert_eq!(h, HourAngle::from_hms(7, 45, 18.946));
+
+    assert_eq!("12°61'0\"".parse::<Degrees>(), Err(Error::InvalidAngle));
+    assert_eq!("12.5°30'".parse::<Degrees>(), Err(Error::InvalidAngle));
+    assert_eq!("".parse::<HourAngle>(), Err(Error::InvalidAngle));
+
+}
@@ -0,0 +1,86 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+use astro::*;
+
+#[test]
+fn conjunctions() {
+
+    use conjunct::ConjunctType::*;
+
+    let jupiter = transit::Body::Planet(planet::Planet::Jupiter);
+    let saturn = transit::Body::Planet(planet::Planet::Saturn);
+
+    // The great conjunction of 2020 December 21
+    let conjs = conjunct::conjunctions(&jupiter, &saturn, 2459180.5, 2459230.5).unwrap();
+    let types: Vec<_> = conjs.iter().map(|c| c.conj_type).collect();
+    assert_eq!(types, vec![InAsc, InLong, ClosestApproach]);
+    assert!((conjs[1].JD - 2459205.265).abs() < 0.01);
+    assert_eq!(util::round_upto_digits(conjs[2].sepr.to_degrees() * 60.0, 1), 6.1);
+    assert!(conjs[2].sepr <= conjs[0].sepr && conjs[2].sepr <= conjs[1].sepr);
+
+    // Meeus, example 18.a, Mercury 2°08' north of Venus on
+    // 1991 August 7.23797 TD
+    let mercury = transit::Body::Planet(planet::Planet::Mercury);
+    let venus = transit::Body::Planet(planet::Planet::Venus);
+    let conjs = conjunct::conjunctions(&mercury, &venus, 2448468.5, 2448478.5).unwrap();
+    assert_eq!(conjs[0].conj_type, InAsc);
+    assert!((conjs[0].JD - 2448475.73797).abs() < 0.0005);
+    assert_eq!(util::round_upto_digits(conjs[0].sepr.to_degrees() * 60.0, 0), 128.0);
+    let north = transit::apprnt_eq_coords(&mercury, conjs[0].JD).dec
+              - transit::apprnt_eq_coords(&venus, conjs[0].JD).dec;
+    assert!(north > 0.0);
+
+    let regulus = transit::Body::Star(star::Star {
+        asc: angle::deg_frm_hms(10, 8, 22.311).to_radians(),
+        dec: angle::deg_frm_dms(11, 58, 1.95).to_radians(),
+        dist: 24.3,
+        radial_vel: 0.00000603,
+        pmotion_asc: angle::deg_frm_dms(0, 0, -0.016951).to_radians(),
+        pmotion_dec: angle::deg_frm_dms(0, 0, 0.00559).to_radians(),
+        JD_epoch: 2451545.0
+    });
+
+    // The Moon passes Regulus once a sidereal month
+    let conjs = conjunct::conjunctions(&transit::Body::Moon, &regulus, 2459230.5, 2459275.5).unwrap();
+    let in_long: Vec<_> = conjs.iter().filter(|c| c.conj_type == InLong).collect();
+    assert_eq!(in_long.len(), 2);
+    assert_eq!(util::round_upto_digits(in_long[1].JD - in_long[0].JD, 0), 27.0);
+    for c in conjs.iter().filter(|c| c.conj_type == ClosestApproach) {
+        assert!(c.sepr.to_degrees() > 4.0);
+    }
+
+    // The Moon occulted Regulus every month from December 2016 to
+    // April 2018, passing it within its semidiameter and parallax
+    let conjs = conjunct::conjunctions(&transit::Body::Moon, &regulus, 2457754.5, 2457845.5).unwrap();
+    let closest: Vec<_> = conjs.iter().filter(|c| c.conj_type == ClosestApproach).collect();
+    assert_eq!(closest.len(), 3);
+    for c in closest {
+        assert!(c.sepr.to_degrees() < 1.2);
+    }
+
+    assert!(conjunct::conjunctions(&jupiter, &transit::Body::Planet(planet::Planet::Earth), 0.0, 1.0).is_err());
+
+}
@@ -0,0 +1,157 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+use astro::*;
+
+#[test]
+fn solar_eclipse() {
+
+    struct TestData(i16, time::Month, f64, eclipse::solar::EclipseType, f64, f64);
+    let test_data = [
+        TestData(2017, time::Month::Aug,  21.0, eclipse::solar::EclipseType::Total,   0.437, 1.031),
+        TestData(2023, time::Month::Apr,  20.0, eclipse::solar::EclipseType::Hybrid, -0.396, 1.013),
+        TestData(2023, time::Month::Oct,  14.0, eclipse::solar::EclipseType::Annular, 0.375, 0.952),
+        TestData(2022, time::Month::Oct,  25.0, eclipse::solar::EclipseType::Partial, 1.070, 0.861),
+        TestData(2024, time::Month::May,   8.0, eclipse::solar::EclipseType::None,    2.822, 0.0),
+    ];
+
+    for data in test_data.iter() {
+        let date = time::Date {
+            year        : data.0,
+            month       : data.1,
+            decimal_day : data.2,
+            cal_type    : time::CalType::Gregorian
+        };
+        let eclipse = eclipse::solar::eclipse(&date);
+
+        assert_eq!(eclipse.eclipse_type, data.3);
+        assert_eq!(util::round_upto_digits(eclipse.gamma, 3), data.4);
+        assert_eq!(util::round_upto_digits(eclipse.mag, 3), data.5);
+    }
+
+}
+
+#[test]
+fn solar_eclipse_local_circumstances() {
+
+    let date = time::Date {
+        year        : 2024,
+        month       : time::Month::Apr,
+        decimal_day : 8.0,
+        cal_type    : time::CalType::Gregorian
+    };
+    let eclipse = eclipse::solar::eclipse(&date);
+    assert_eq!(util::round_upto_digits(eclipse.JD, 3), 2460409.263);
+
+    let delta_t = 69.0;
+    let dallas = coords::GeographPoint {
+        long: 96.797_f64.to_radians(),
+        lat: 32.7767_f64.to_radians()
+    };
+    let loc = eclipse::solar::local_circumstances(&eclipse, &dallas, 0.0, delta_t);
+
+    assert_eq!(loc.eclipse_type, eclipse::solar::EclipseType::Total);
+    assert_eq!(loc.obscuration, 1.0);
+    assert_eq!(util::round_upto_digits(loc.sun_alt.to_degrees(), 0), 65.0);
+
+    let utc_minutes = |JD: f64| {
+        let day = JD - delta_t/86400.0 + 0.5;
+        ((day - day.floor()) * 1440.0).round()
+    };
+    assert_eq!(utc_minutes(loc.C1.unwrap()), (17*60 + 23) as f64);
+    assert_eq!(utc_minutes(loc.C2.unwrap()), (18*60 + 41) as f64);
+    assert_eq!(utc_minutes(loc.C3.unwrap()), (18*60 + 45) as f64);
+    assert_eq!(utc_minutes(loc.C4.unwrap()), (20*60 + 3) as f64);
+
+}
+
+#[test]
+fn lunar_eclipse() {
+
+    struct TestData(i16, time::Month, f64, eclipse::lunar::EclipseType, f64, f64);
+    let test_data = [
+        TestData(2021, time::Month::May, 26.0, eclipse::lunar::EclipseType::Total,      0.48,  1.009),
+        TestData(2021, time::Month::Nov, 19.0, eclipse::lunar::EclipseType::Partial,   -0.46,  0.974),
+        TestData(2023, time::Month::May,  5.0, eclipse::lunar::EclipseType::Penumbral, -1.03, -0.045),
+    ];
+
+    for data in test_data.iter() {
+        let date = time::Date {
+            year        : data.0,
+            month       : data.1,
+            decimal_day : data.2,
+            cal_type    : time::CalType::Gregorian
+        };
+        let eclipse = eclipse::lunar::eclipse(&date, &eclipse::lunar::ShadowRule::Danjon);
+
+        assert_eq!(eclipse.eclipse_type, data.3);
+        assert_eq!(util::round_upto_digits(eclipse.gamma.unwrap(), 2), data.4);
+        assert_eq!(util::round_upto_digits(eclipse.umbral_mag, 3), data.5);
+    }
+
+    let date = time::Date {
+        year        : 2024,
+        month       : time::Month::Jan,
+        decimal_day : 25.0,
+        cal_type    : time::CalType::Gregorian
+    };
+    let eclipse = eclipse::lunar::eclipse(&date, &eclipse::lunar::ShadowRule::Danjon);
+    assert_eq!(eclipse.eclipse_type, eclipse::lunar::EclipseType::None);
+    assert_eq!(eclipse.gamma, None);
+
+}
+
+#[test]
+fn lunar_eclipse_contacts() {
+
+    let date = time::Date {
+        year        : 2022,
+        month       : time::Month::Nov,
+        decimal_day : 8.0,
+        cal_type    : time::CalType::Gregorian
+    };
+
+    let minutes = |JD: f64| {
+        let day = JD + 0.5;
+        ((day - day.floor()) * 1440.0).round()
+    };
+
+    let danjon = eclipse::lunar::eclipse(&date, &eclipse::lunar::ShadowRule::Danjon);
+    assert_eq!(util::round_upto_digits(danjon.pen_mag, 2), 2.41);
+    assert_eq!(util::round_upto_digits(danjon.umbral_mag, 2), 1.36);
+    assert_eq!(minutes(danjon.P1.unwrap()), ( 8*60 +  3) as f64);
+    assert_eq!(minutes(danjon.U1.unwrap()), ( 9*60 + 10) as f64);
+    assert_eq!(minutes(danjon.U2.unwrap()), (10*60 + 18) as f64);
+    assert_eq!(minutes(danjon.JD),          (11*60 +  0) as f64);
+    assert_eq!(minutes(danjon.U3.unwrap()), (11*60 + 43) as f64);
+    assert_eq!(minutes(danjon.U4.unwrap()), (12*60 + 50) as f64);
+    assert_eq!(minutes(danjon.P4.unwrap()), (13*60 + 57) as f64);
+
+    let chauvenet = eclipse::lunar::eclipse(&date, &eclipse::lunar::ShadowRule::Chauvenet);
+    assert!(chauvenet.umbral_mag > danjon.umbral_mag);
+    assert!(chauvenet.P1.unwrap() < danjon.P1.unwrap());
+    assert!(chauvenet.P4.unwrap() > danjon.P4.unwrap());
+
+}
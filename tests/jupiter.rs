@@ -69,3 +69,108 @@ fn moons() {
     }
 
 }
+
+#[test]
+fn moon_phenomena() {
+
+    use planet::jupiter::moon::{Moon, PhenomenonType};
+
+    let phenomena = planet::jupiter::moon::phenomena(2459000.5, 2459004.5);
+
+    let io: Vec<_> = phenomena.iter().filter(|p| p.moon == Moon::Io).collect();
+    assert_eq!(io[0].phenom_type, PhenomenonType::ShadowIngress);
+    assert_eq!(io[1].phenom_type, PhenomenonType::TransitIngress);
+    assert_eq!(io[2].phenom_type, PhenomenonType::ShadowEgress);
+    assert_eq!(io[3].phenom_type, PhenomenonType::TransitEgress);
+
+    // Io takes a little over two hours to cross the disk
+    let duration = (io[3].JD - io[1].JD) * 24.0;
+    assert!(duration > 2.0 && duration < 2.5);
+
+    // Io is at the limb at the start of the transit
+    let (X, Y) = planet::jupiter::moon::apprnt_rect_coords(io[1].JD, &Moon::Io);
+    assert_eq!(util::round_upto_digits(X*X + Y*Y, 1), 1.0);
+
+    // The triple shadow transit of 2015 January 24, with the published
+    // times in UT, good to a minute, and a delta T of 68 seconds
+    let phenomena_2015 = planet::jupiter::moon::phenomena(2457046.5, 2457047.0);
+    let UT_of = |moon: Moon, phenom_type: PhenomenonType| {
+        let p = phenomena_2015.iter()
+            .find(|p| p.moon == moon && p.phenom_type == phenom_type)
+            .unwrap();
+        (p.JD - 68.0/86400.0 - 2457046.5) * 24.0 * 60.0
+    };
+    let published = [
+        (Moon::Callisto, PhenomenonType::ShadowIngress,  3*60 + 11),
+        (Moon::Io,       PhenomenonType::ShadowIngress,  4*60 + 35),
+        (Moon::Europa,   PhenomenonType::ShadowIngress,  6*60 + 27),
+        (Moon::Io,       PhenomenonType::ShadowEgress,   6*60 + 52),
+    ];
+    for &(moon, phenom_type, minutes) in &published {
+        assert!((UT_of(moon, phenom_type) - (minutes as f64)).abs() < 1.5);
+    }
+
+    for p in &phenomena {
+        assert!(p.JD >= 2459000.5 && p.JD <= 2459004.5);
+    }
+    for pair in phenomena.windows(2) {
+        assert!(pair[0].JD <= pair[1].JD);
+    }
+
+}
+
+#[test]
+fn moons_XYZ() {
+
+    use planet::jupiter::moon::Moon;
+
+    // Meeus, example 44.b
+    let JD = 2448972.50068;
+    let data = [
+        (Moon::Io,       -3.4503, 0.2137),
+        (Moon::Europa,    7.4418, 0.2752),
+        (Moon::Ganymede,  1.2010, 0.5900),
+        (Moon::Callisto,  7.0720, 1.0291),
+    ];
+
+    for &(ref moon, X_meeus, Y_meeus) in &data {
+        let (X, Y, Z) = planet::jupiter::moon::apprnt_rect_coords_XYZ(JD, moon);
+        assert!((X - X_meeus).abs() < 0.00015 && (Y - Y_meeus).abs() < 0.00015);
+
+        // All four moons are then nearer to the Earth than Jupiter
+        assert!(Z > 0.0);
+    }
+
+    // At opposition the Sun and the Earth see the moons from almost the
+    // same direction, and a quarter of a year later they don't
+    let (occult_sepr, eclipse_sepr) = planet::jupiter::moon::mutual_sepr(2459044.834, &Moon::Io, &Moon::Callisto);
+    assert!((occult_sepr - eclipse_sepr).abs() < 0.01);
+    let (occult_sepr, eclipse_sepr) = planet::jupiter::moon::mutual_sepr(2459134.834, &Moon::Io, &Moon::Callisto);
+    assert!((occult_sepr - eclipse_sepr).abs() > 1.0);
+
+}
+
+#[test]
+fn central_meridian_transits() {
+
+    use planet::jupiter::RotSystem;
+
+    let long = 340_f64.to_radians();
+    let transits = planet::jupiter::central_meridian_transits(&RotSystem::II, long, 2459000.5, 2459002.5);
+
+    assert_eq!(transits.len(), 5);
+    for &JD in &transits {
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        let eph = planet::jupiter::ephemeris(JD, ecliptic::mn_oblq_laskar(JD), nut_in_long, nut_in_oblq);
+        assert_eq!(util::round_upto_digits(eph.w2.to_degrees(), 5), 340.0);
+    }
+
+    // The rotation period in System II is about 9h 55m
+    let period = (transits[4] - transits[0]) / 4.0 * 24.0;
+    assert_eq!(util::round_upto_digits(period, 1), 9.9);
+
+    let transits_III = planet::jupiter::central_meridian_transits(&RotSystem::III, long, 2459000.5, 2459002.5);
+    assert_eq!(transits_III.len(), 4);
+    assert!(transits_III.iter().zip(transits.iter()).all(|(a, b)| a != b));
+
+}
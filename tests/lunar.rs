@@ -125,3 +125,35 @@ fn phases() {
     let JD_new_moon = lunar::time_of_phase(&date_new_moon, &lunar::Phase::New);
     assert_eq!(util::round_upto_digits(JD_new_moon, 5), 2443192.65118);
 }
+
+#[test]
+fn time_of_apsis() {
+
+    let date = time::Date {
+        year        : 1988,
+        month       : time::Month::Oct,
+        decimal_day : 1.0,
+        cal_type    : time::CalType::Gregorian
+    };
+
+    let (JD, dist) = lunar::time_of_apsis(&date, &lunar::Apsis::Apogee);
+    assert!((JD - 2447442.3543).abs() < 0.005);
+    assert_eq!(util::round_upto_digits(dist, 0), 405978.0);
+
+}
+
+#[test]
+fn time_of_greatest_dec() {
+
+    let date = time::Date {
+        year        : 1988,
+        month       : time::Month::Dec,
+        decimal_day : 15.0,
+        cal_type    : time::CalType::Gregorian
+    };
+
+    let (JD, dec) = lunar::time_of_greatest_dec(&date, &lunar::DecExtreme::North);
+    assert!((JD - 2447518.3346).abs() < 0.005);
+    assert_eq!(util::round_upto_digits(dec.to_degrees(), 3), 28.154);
+
+}
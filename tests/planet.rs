@@ -42,6 +42,34 @@ fn heliocent_coords() {
 
 }
 
+#[test]
+fn heliocent_coords_trunc() {
+
+    use planet::Planet::*;
+
+    let planets = [Mercury, Venus, Earth, Mars, Jupiter, Saturn, Uranus, Neptune];
+
+    for planet in planets.iter() {
+        for &arcsec in [1.0, 6.0, 60.0].iter() {
+            let accuracy = angle::deg_frm_dms(0, 0, arcsec).to_radians();
+
+            // From the year -2000 to the year 6000
+            let mut JD = 990557.5;
+            while JD < 3912707.5 {
+                let (L, B, R) = planet::heliocent_coords(planet, JD);
+                let (L1, B1, R1) = planet::heliocent_coords_trunc(planet, JD, accuracy);
+
+                assert!((L - L1).sin().abs() < accuracy);
+                assert!((B - B1).sin().abs() < accuracy);
+                assert!((R - R1).abs() < accuracy * R);
+
+                JD += 182622.5;
+            }
+        }
+    }
+
+}
+
 #[test]
 #[allow(unused_variables)]
 fn geocent_geomet_ecl_coords() {
@@ -95,3 +123,243 @@ fn geocent_apprnt_ecl_coords() {
     assert_eq!((L, B, R), (313.08, -2.08, 0.9109));
     
 }
+
+#[test]
+fn geocent_apprnt_eq_coords() {
+
+    // Meeus, example 33.a, with aberration, nutation and the FK5
+    // correction: 21h 04m 41.454s, -18° 53' 16.84"
+    let (planet_eq_point, _) = planet::geocent_apprnt_eq_coords (
+        &planet::Planet::Venus, 2448976.5
+    );
+    let asc = angle::limit_to_360(planet_eq_point.asc.to_degrees());
+    let dec = planet_eq_point.dec.to_degrees();
+
+    assert!((asc - angle::deg_frm_hms(21, 4, 41.454)).abs()*3600.0 < 0.01);
+    assert!((dec - angle::deg_frm_dms(-18, -53, -16.84)).abs()*3600.0 < 0.01);
+
+}
+
+#[test]
+fn invalid_planet() {
+
+    assert_eq!(
+        planet::semidiameter(&planet::Planet::Earth, 1.0),
+        Err(Error::InvalidPlanet {
+            planet: planet::Planet::Earth,
+            function: "planet::semidiameter"
+        })
+    );
+
+    match planet::apprnt_mag_84(&planet::Planet::Saturn, 0.0, 1.0, 1.0) {
+        Err(Error::InvalidPlanet { planet: planet::Planet::Saturn, .. }) => (),
+        x => panic!("unexpected {:?}", x),
+    }
+
+}
+
+#[test]
+fn VSOP87_variants() {
+
+    let JD = 2451545.0;
+
+    let (x, y, z) = planet::heliocent_rect_coords_J2000(&planet::Planet::Earth, JD);
+    assert_eq!(util::round_upto_digits(x, 7), -0.1771355);
+    assert_eq!(util::round_upto_digits(y, 7), 0.9672416);
+    assert_eq!(util::round_upto_digits(z, 7), -0.0000039);
+
+    // At J2000.0 the ecliptic of the date is that of J2000.0
+    let (xc, yc, zc) = planet::heliocent_rect_coords(&planet::Planet::Earth, JD);
+    assert!((x - xc).abs() < 1e-9 && (y - yc).abs() < 1e-9 && (z - zc).abs() < 1e-9);
+
+    // The Earth on 1992 Oct 13.0 TD, from Meeus's examples 25.b and
+    // 26.a. Meeus works from a truncated VSOP87, which puts both of his
+    // positions about 10⁻⁶ AU away from the full series, but his shift
+    // from the equinox of the date to J2000.0 is matched to the
+    // precision of the published values.
+    let JD = 2448908.5;
+    let meeus_date = (0.9379950, 0.3396860, -0.0000031);
+    let meeus_J2000 = (0.9373957, 0.3413362, 0.0000038);
+    let (x, y, z) = planet::heliocent_rect_coords_J2000(&planet::Planet::Earth, JD);
+    let (xd, yd, zd) = planet::heliocent_rect_coords(&planet::Planet::Earth, JD);
+    assert!((x - meeus_J2000.0).abs() < 2e-6);
+    assert!((y - meeus_J2000.1).abs() < 2e-6);
+    assert!((z - meeus_J2000.2).abs() < 2e-6);
+    assert!(((x - xd) - (meeus_J2000.0 - meeus_date.0)).abs() < 2e-7);
+    assert!(((y - yd) - (meeus_J2000.1 - meeus_date.1)).abs() < 2e-7);
+    assert!(((z - zd) - (meeus_J2000.2 - meeus_date.2)).abs() < 2e-7);
+
+    let (xs, ys, zs) = planet::sun_barycent_rect_coords(2451545.0);
+    assert_eq!(util::round_upto_digits((xs*xs + ys*ys + zs*zs).sqrt(), 4), 0.0077);
+
+}
+
+#[test]
+fn heliocent_vel() {
+
+    let h = 0.01;
+
+    // The turning of the frame of the date adds about 10⁻⁶ AU per day to
+    // the velocity of Mars, and 2·10⁻⁵ AU per day to that of Neptune
+    for planet in [planet::Planet::Mars, planet::Planet::Neptune].iter() {
+        for &JD in [2086302.5, 2451545.0, 2816787.5].iter() {
+            let (x1, y1, z1) = planet::heliocent_rect_coords_J2000(planet, JD - h);
+            let (x2, y2, z2) = planet::heliocent_rect_coords_J2000(planet, JD + h);
+            let (vx, vy, vz) = planet::heliocent_rect_vel_J2000(planet, JD);
+
+            assert!((vx - (x2 - x1)/(2.0*h)).abs() < 1e-9);
+            assert!((vy - (y2 - y1)/(2.0*h)).abs() < 1e-9);
+            assert!((vz - (z2 - z1)/(2.0*h)).abs() < 1e-9);
+        }
+    }
+
+    let JD = 2451545.0;
+
+    let (L_rate, _, R_rate) = planet::heliocent_coords_rates(&planet::Planet::Earth, JD);
+    assert_eq!(util::round_upto_digits(L_rate.to_degrees(), 3), 1.019);
+    assert!(R_rate.abs() < 0.0001);
+
+    let (vx, vy, vz) = planet::heliocent_rect_vel(&planet::Planet::Earth, JD);
+    assert_eq!(util::round_upto_digits((vx*vx + vy*vy + vz*vz).sqrt(), 4), 0.0175);
+
+}
+
+#[test]
+fn phenomena() {
+
+    use planet::phenomena::PhenomenonType::*;
+
+    // 2020 Jan 1 to 2021 Jan 1
+    let (JD_start, JD_end) = (2458849.5, 2459215.5);
+
+    let venus = planet::phenomena::phenomena(&planet::Planet::Venus, JD_start, JD_end).unwrap();
+    let types: Vec<_> = venus.iter().map(|p| p.phenom_type).collect();
+    assert_eq!(
+        types,
+        vec![GreatestElongEast, StationRetrograde, InferiorConjunction, StationDirect, GreatestElongWest]
+    );
+    assert_eq!(util::round_upto_digits(venus[0].elong.to_degrees(), 1), 46.1);
+    assert!((venus[2].JD - 2459004.239).abs() < 0.01);
+
+    let mars = planet::phenomena::phenomena(&planet::Planet::Mars, JD_start, JD_end).unwrap();
+    let types: Vec<_> = mars.iter().map(|p| p.phenom_type).collect();
+    assert_eq!(types, vec![StationRetrograde, Opposition, StationDirect]);
+    assert!((mars[1].JD - 2459136.473).abs() < 0.01);
+
+    // Opposition of Jupiter, 2020 Jul 14 at 8h UT. Leaving out the Sun's
+    // aberration would put it a quarter of an hour early.
+    let jupiter = planet::phenomena::phenomena(&planet::Planet::Jupiter, JD_start, JD_end).unwrap();
+    assert_eq!(jupiter[1].phenom_type, Opposition);
+    assert!((jupiter[1].JD - 2459044.834).abs() < 0.003);
+
+    assert!(planet::phenomena::phenomena(&planet::Planet::Earth, JD_start, JD_end).is_err());
+
+}
+
+#[test]
+fn apsis() {
+
+    use planet::apsis::Apsis;
+
+    assert_eq!(
+        util::round_upto_digits(planet::apsis::approx_passage(&planet::Planet::Venus, &Apsis::Perihelion, 2443870.0), 3),
+        2443873.704
+    );
+    assert_eq!(
+        util::round_upto_digits(planet::apsis::approx_passage(&planet::Planet::Mars, &Apsis::Aphelion, 2463500.0), 3),
+        2463530.456
+    );
+
+    // 2020 January 5, 07:48 UT and July 4, 11:35 UT
+    let (JD, R) = planet::apsis::passage(&planet::Planet::Earth, &Apsis::Perihelion, 2458849.5);
+    assert!((JD - 2458853.826).abs() < 0.01);
+    assert_eq!(util::round_upto_digits(R, 5), 0.98324);
+    let (JD, R) = planet::apsis::passage(&planet::Planet::Earth, &Apsis::Aphelion, 2459034.5);
+    assert!((JD - 2459034.983).abs() < 0.01);
+    assert_eq!(util::round_upto_digits(R, 5), 1.01669);
+
+    // The outer planets, whose mean orbits are off by weeks to years,
+    // against the published dates of their recent and coming passages
+    let passages = [
+        (planet::Planet::Jupiter, Apsis::Perihelion, 2459965.5), // 2023 Jan 21
+        (planet::Planet::Saturn,  Apsis::Perihelion, 2452846.5), // 2003 Jul 26
+        (planet::Planet::Saturn,  Apsis::Aphelion,   2458225.5), // 2018 Apr 17
+        (planet::Planet::Uranus,  Apsis::Aphelion,   2454889.5), // 2009 Feb 27
+        (planet::Planet::Uranus,  Apsis::Perihelion, 2470035.5), // 2050 Aug 17
+        (planet::Planet::Neptune, Apsis::Aphelion,   2436762.5), // 1959 Jul 13
+    ];
+    for &(ref planet, ref apsis, JD_published) in &passages {
+        let JD_approx = planet::apsis::approx_passage(planet, apsis, JD_published);
+        let (JD, _) = planet::apsis::passage(planet, apsis, JD_approx);
+        assert!((JD - JD_published).abs() < 1.0);
+    }
+
+}
+
+#[test]
+fn physical_ephemeris() {
+
+    // Meeus, example 42.a, with the IAU rotation elements of Mars
+    let JD = 2448935.500638;
+    let eph = planet::physical::ephemeris(
+        &planet::Planet::Mars,
+        JD,
+        23.44022_f64.to_radians(),
+        angle::deg_frm_dms(0, 0, 15.42).to_radians(),
+        angle::deg_frm_dms(0, 0, -1.0).to_radians(),
+    ).unwrap();
+
+    // Meeus gives the areocentric values; De and Ds are areographic
+    let (a, b) = planet::physical::radii(&planet::Planet::Mars);
+    let areocent = |lat: f64| (lat.tan() * (b/a).powi(2)).atan().to_degrees();
+    assert_eq!(util::round_upto_digits(areocent(eph.De), 2), 12.44);
+    assert_eq!(util::round_upto_digits(areocent(eph.Ds), 2), -2.76);
+    assert_eq!(util::round_upto_digits(eph.De.to_degrees(), 2), 12.58);
+    assert_eq!(util::round_upto_digits(angle::limit_to_360(eph.P.to_degrees()), 2), 347.64);
+    assert_eq!(util::round_upto_digits(eph.w.to_degrees(), 1), 111.5);
+    assert_eq!(util::round_upto_digits(eph.d.to_degrees() * 3600.0, 1), 10.8);
+    assert_eq!(util::round_upto_digits(eph.q.to_degrees() * 3600.0, 2), 1.06);
+
+    // Venus, a day after inferior conjunction
+    let eph = planet::physical::ephemeris(&planet::Planet::Venus, 2459004.5, 23.44_f64.to_radians(), 0.0, 0.0).unwrap();
+    assert!(eph.i.to_degrees() > 170.0);
+    assert_eq!(util::round_upto_digits(eph.d.to_degrees() * 3600.0, 0), 58.0);
+
+    assert!(planet::physical::ephemeris(&planet::Planet::Earth, JD, 0.0, 0.0, 0.0).is_err());
+
+    let eph_on = |planet: &planet::Planet, JD: f64| {
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        planet::physical::ephemeris(planet, JD, ecliptic::mn_oblq_IAU(JD), nut_in_long, nut_in_oblq).unwrap()
+    };
+
+    // Mercury's axis is within about 2' of the normal to its orbit
+    for JD in [2446448.5, 2453371.5, 2454441.5, 2460676.5].iter() {
+        assert!(eph_on(&planet::Planet::Mercury, *JD).Ds.to_degrees().abs() < 0.05);
+    }
+
+    // Equinox of Uranus, 2007 December 7
+    let eph = eph_on(&planet::Planet::Uranus, 2454441.5);
+    assert!(eph.Ds.to_degrees().abs() < 0.05);
+    assert_eq!(util::round_upto_digits(eph.De.to_degrees(), 1), -2.9);
+
+    // Voyager 2 at Uranus, 1986 January 24, near the southern solstice,
+    // when the sub-solar latitude approached -(180 - 97.77) degrees
+    // planet-centric, or -82.6 planetographic
+    let eph = eph_on(&planet::Planet::Uranus, 2446454.5);
+    assert_eq!(util::round_upto_digits(eph.Ds.to_degrees(), 1), -82.5);
+
+    // Uranus rotates retrogradely, and its longitudes increase eastwards,
+    // so the central meridian advances by 501.16 degrees a day
+    let w1 = eph_on(&planet::Planet::Uranus, 2460676.5).w;
+    let w2 = eph_on(&planet::Planet::Uranus, 2460676.6).w;
+    assert_eq!(util::round_upto_digits(angle::limit_to_360((w2 - w1).to_degrees()), 1), 50.1);
+
+    // Neptune near its southern solstice of 2005, with an obliquity of
+    // 28.32 degrees, or 29.16 planetographic
+    let eph = eph_on(&planet::Planet::Neptune, 2453500.5);
+    assert_eq!(util::round_upto_digits(eph.Ds.to_degrees(), 1), -29.2);
+    let w1 = eph_on(&planet::Planet::Neptune, 2460676.5).w;
+    let w2 = eph_on(&planet::Planet::Neptune, 2460676.6).w;
+    assert_eq!(util::round_upto_digits(angle::limit_to_360((w2 - w1).to_degrees()), 1), 53.6);
+
+}
@@ -79,3 +79,64 @@ fn moons() {
     }
 
 }
+
+#[test]
+fn ring_events() {
+
+    use planet::saturn::ring::RingEventType;
+
+    // 2025, with the Earth crossing on March 23 and Saturn's equinox
+    // on May 6
+    let events = planet::saturn::ring::events(2460676.5, 2461041.5);
+    let types: Vec<_> = events.iter().map(|e| e.event_type).collect();
+    assert_eq!(types, vec![RingEventType::EarthCrossing, RingEventType::SunCrossing]);
+    assert!((events[0].JD - 2460758.08).abs() < 0.5);
+    assert!((events[1].JD - 2460801.52).abs() < 0.5);
+    assert!(events[0].B.abs() < 1e-9);
+
+    // 1995-96, with three Earth crossings around Saturn's equinox of
+    // 1995 November 19
+    let events = planet::saturn::ring::events(2449718.5, 2450449.5);
+    let types: Vec<_> = events.iter().map(|e| e.event_type).collect();
+    assert_eq!(types, vec![
+        RingEventType::EarthCrossing,
+        RingEventType::EarthCrossing,
+        RingEventType::SunCrossing,
+        RingEventType::EarthCrossing
+    ]);
+    assert!((events[0].JD - 2449859.39).abs() < 0.5);
+    assert!((events[1].JD - 2449940.72).abs() < 0.5);
+    assert!((events[2].JD - 2450040.42).abs() < 0.5);
+    assert!((events[3].JD - 2450125.30).abs() < 0.5);
+
+    // Ranges ending just before, or starting just after, a crossing
+    let events = planet::saturn::ring::events(2449950.5, 2450037.5);
+    assert!(events.is_empty());
+    let events = planet::saturn::ring::events(2450043.5, 2450100.5);
+    assert!(events.is_empty());
+    let events = planet::saturn::ring::events(2449718.5, 2449859.0);
+    assert!(events.is_empty());
+
+    // 1936, with two nearly tangent crossings less than five days apart.
+    // The search samples B every 10 days from 2700 days before the start
+    // of the range, and this range puts both crossings between a pair of
+    // samples.
+    let events = planet::saturn::ring::events(2428205.5, 2428570.5);
+    let types: Vec<_> = events.iter().map(|e| e.event_type).collect();
+    assert_eq!(types, vec![
+        RingEventType::EarthCrossing,
+        RingEventType::EarthCrossing,
+        RingEventType::SunCrossing
+    ]);
+    assert!((events[0].JD - 2428345.97).abs() < 0.5);
+    assert!((events[1].JD - 2428350.53).abs() < 0.5);
+    assert!(events[0].B.abs() < 1e-9 && events[1].B.abs() < 1e-9);
+
+    // The ring was widest in October 2017
+    let events = planet::saturn::ring::events(2457754.5, 2458119.5);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, RingEventType::MaxOpening);
+    assert!((events[0].JD - 2458043.13).abs() < 1.0);
+    assert_eq!(util::round_upto_digits(events[0].B.to_degrees(), 1), 27.0);
+
+}
@@ -44,3 +44,28 @@ fn eq_coords_frm_motion() {
     assert_eq!((d2, m2, util::round_upto_digits(s2, 1)), (-16, -22, -56.0));
 
 }
+
+#[test]
+fn apprnt_eq_coords() {
+
+    // Meeus, example 23.a, theta Persei on 2028 November 13.19 TD
+    let theta_persei = star::Star {
+        asc: angle::deg_frm_hms(2, 44, 11.986).to_radians(),
+        dec: angle::deg_frm_dms(49, 13, 42.48).to_radians(),
+        dist: 1.0,
+        radial_vel: 0.0,
+        pmotion_asc: angle::deg_frm_dms(0, 0, 0.03425).to_radians(),
+        pmotion_dec: angle::deg_frm_dms(0, 0, -0.0895).to_radians(),
+        JD_epoch: 2451545.0
+    };
+
+    let eq_point = star::apprnt_eq_coords(&theta_persei, 2462088.69);
+
+    let (h, m, s) = angle::hms_frm_deg(eq_point.asc.to_degrees());
+    assert_eq!((h, m), (2, 46));
+    assert!((s - 14.390).abs() < 0.002);
+
+    let (d, m, s) = angle::dms_frm_deg(eq_point.dec.to_degrees());
+    assert_eq!((d, m, util::round_upto_digits(s, 2)), (49, 21, 7.45));
+
+}
@@ -94,3 +94,72 @@ fn approx_synd_rot() {
     );
 
 }
+
+#[test]
+fn season() {
+
+    // Test taken from Meeus 2nd ed. on page 180
+
+    let approx_JD = sun::season::approx_time(1962, &sun::season::Season::JuneSolstice);
+    assert_eq!(util::round_upto_digits(approx_JD, 5), 2437837.39245);
+
+    let JD = sun::season::time(1962, &sun::season::Season::JuneSolstice);
+    assert_eq!(util::round_upto_digits(JD, 5), 2437837.39215);
+
+    let (mar, june, sept, dec) = sun::season::times_in_year(2000);
+    assert_eq!(util::round_upto_digits(mar, 4), 2451623.8169);
+    assert_eq!(util::round_upto_digits(june, 4), 2451716.5755);
+    assert_eq!(util::round_upto_digits(sept, 4), 2451810.2282);
+    assert_eq!(util::round_upto_digits(dec, 4), 2451900.0684);
+
+    let seasons = [
+        sun::season::Season::MarEquinox,
+        sun::season::Season::JuneSolstice,
+        sun::season::Season::SeptEquinox,
+        sun::season::Season::DecSolstice
+    ];
+
+    let mut year = -1000;
+    while year <= 3000 {
+        for (k, season) in seasons.iter().enumerate() {
+            let JD = sun::season::time(year, season);
+            let long = sun::season::apprnt_long(JD);
+            let target = (k as f64) * 90_f64.to_radians();
+
+            assert!((long - target).sin().abs() < 0.000001_f64.to_radians());
+            assert!((sun::season::approx_time(year, season) - JD).abs() < 120.0/86400.0);
+        }
+        year += 250;
+    }
+
+}
+
+#[test]
+fn geocent_rect_coords_J2000_B1950() {
+
+    // Meeus, example 26.a, 1992 October 13.0 TD
+    let JD = 2448908.5;
+
+    // Meeus used his truncated VSOP87 series, which differs from the
+    // full series used here by about 1e-6 AU, so the absolute values
+    // are compared to 2e-6 AU
+    let close = |a: (f64, f64, f64), b: (f64, f64, f64), tol: f64|
+        (a.0 - b.0).abs() < tol && (a.1 - b.1).abs() < tol && (a.2 - b.2).abs() < tol;
+
+    let date = sun::geocent_rect_coords_frm_J2000(JD, JD);
+    let J2000 = sun::geocent_rect_coords_J2000(JD);
+    let B1950 = sun::geocent_rect_coords_B1950(JD);
+
+    assert!(close(date, (-0.9379952, -0.3116544, -0.1351215), 2e-6));
+    assert!(close(J2000, (-0.9373959, -0.3131679, -0.1357792), 2e-6));
+    assert!(close(B1950, (-0.9414870, -0.3026656, -0.1312140), 2e-6));
+
+    // The series error cancels in the change of frame, which matches
+    // Meeus to 7 digits
+    let shift = (J2000.0 - date.0, J2000.1 - date.1, J2000.2 - date.2);
+    assert!(close(shift, (0.0005993, -0.0015135, -0.0006577), 1e-7));
+
+    let (x1, y1, z1) = sun::geocent_rect_coords_frm_J2000(JD, 2433282.4235);
+    assert!(close(B1950, (x1, y1, z1), 1e-8));
+
+}
@@ -121,4 +121,201 @@ fn date_frm_julian_day() {
 
     }
 
+    assert_eq!(time::date_frm_julian_day(-1.0), Err(Error::JulianDayOutOfRange(-1.0)));
+
+}
+
+#[test]
+fn time_scales() {
+
+    use astro::time::scale::*;
+
+    let secs = |a: &Instant, b: &Instant| (b.JD - a.JD) * 86400.0;
+
+    // Example taken from the SOFA time scale cookbook
+
+    let UTC = Instant::frm_date(
+        &time::Date {
+            year: 2006,
+            month: time::Month::Jan,
+            decimal_day: time::decimal_day(&time::DayOfMonth {
+                day: 15, hr: 21, min: 24, sec: 37.5, time_zone: 0.0
+            }),
+            cal_type: time::CalType::Gregorian
+        },
+        TimeScale::UTC
+    );
+    let TT = UTC.to(TimeScale::TT);
+    let TDB = UTC.to(TimeScale::TDB);
+
+    assert_eq!(util::round_upto_digits(secs(&UTC, &UTC.to(TimeScale::TAI)), 3), 33.0);
+    assert_eq!(util::round_upto_digits(secs(&UTC, &TT), 3), 65.184);
+    assert!((TDB_minus_TT(TT.JD) - 0.000373).abs() < 0.00001);
+    assert_eq!(util::round_upto_digits(secs(&TT, &TDB), 3), 0.0);
+
+    let config = Config { ut1: UT1Model::DUT1(0.3341), ..Config::default() };
+    assert_eq!(
+        util::round_upto_digits(secs(&UTC, &UTC.to_with(TimeScale::UT1, &config)), 4),
+        0.3341
+    );
+
+    // Leap seconds, and the drifting offsets of UTC before 1972
+
+    let before = Instant::new(2457754.4999, TimeScale::UTC);
+    let after = Instant::new(2457754.5, TimeScale::UTC);
+    assert_eq!(util::round_upto_digits(secs(&before, &before.to(TimeScale::TT)), 3), 68.184);
+    assert_eq!(util::round_upto_digits(secs(&after, &after.to(TimeScale::TT)), 3), 69.184);
+
+    let leap_secs = LeapSecTable::builtin();
+    assert_eq!(util::round_upto_digits(leap_secs.TAI_minus_UTC(2437665.5), 6), 1.845858);
+    assert_eq!(util::round_upto_digits(leap_secs.TAI_minus_UTC(2439126.5 + 365.0), 6), 5.259250);
+
+    let mut config = Config::default();
+    config.leap_secs.insert(2461041.5, 38.0);
+    let later = Instant::new(2461100.0, TimeScale::UTC);
+    assert_eq!(
+        util::round_upto_digits(secs(&later, &later.to_with(TimeScale::TT, &config)), 3),
+        70.184
+    );
+
+    // Conversions are reversible
+
+    let scales = [
+        TimeScale::UTC, TimeScale::TAI, TimeScale::TT, TimeScale::TDB, TimeScale::UT1
+    ];
+    for JD in [2299160.5, 2438000.25, 2451545.0, 2460000.75].iter() {
+        for a in scales.iter() {
+            for b in scales.iter() {
+                let t = Instant::new(*JD, *a);
+                assert!((t.to(*b).to(*a).JD - *JD).abs() * 86400.0 < 0.0001);
+            }
+        }
+    }
+
+}
+
+#[test]
+fn delta_t_models() {
+
+    use astro::time::delta_t_model::*;
+    use astro::time::scale::LeapSecTable;
+
+    let tabulated = Model::tabulated();
+
+    // Test taken from Meeus 2nd ed. on page 79
+    assert_eq!(util::round_upto_digits(tabulated.delta_t(1977.13), 0), 48.0);
+
+    assert_eq!(tabulated.delta_t(1900.0), -2.8);
+    assert!((tabulated.delta_t(2000.0) - Model::EspenakMeeus.delta_t(2000.0)).abs() < 0.1);
+
+    // The long-term parabola joins on to the ends of the table
+    let table = Table::builtin();
+    for &year in [table.start(), table.end()].iter() {
+        assert!((tabulated.delta_t(year - 0.001) - tabulated.delta_t(year + 0.001)).abs() < 0.01);
+    }
+    assert_eq!(Model::LongTermParabola.delta_t(1820.0), -20.0);
+    assert_eq!(
+        util::round_upto_digits(tabulated.delta_t(-500.0), 1),
+        util::round_upto_digits(long_term_parabola(-500.0) + 121.0 - long_term_parabola(1620.0), 1)
+    );
+
+    assert_eq!(lunar_accel_correction(1955.0, -23.8946), 0.0);
+    assert!(lunar_accel_correction(0.0, -23.8946) < -700.0);
+
+    // A table from IERS finals data takes precedence over the one built in
+
+    let finals_line = |MJD: f64, UT1_minus_UTC: f64| {
+        let mut line = format!("{:7}{:8.2}", "", MJD);
+        line.push_str(&" ".repeat(58 - line.len()));
+        line.push_str(&format!("{:10.7}", UT1_minus_UTC));
+        line
+    };
+    let text = [
+        finals_line(58849.0, -0.1771),
+        finals_line(58850.0, -0.1777),
+        finals_line(58851.0, -0.1782),
+        format!("{:7}{:8.2}", "", 58852.0),
+    ].join("\n");
+
+    let leap_secs = LeapSecTable::builtin();
+    let finals = Table::parse_finals(&text, &leap_secs).unwrap();
+    let model = Model::Tabulated(vec![finals.clone(), Table::builtin()]);
+    assert_eq!(
+        util::round_upto_digits(model.delta_t(finals.start()), 4),
+        util::round_upto_digits(32.184 + 37.0 + 0.1771, 4)
+    );
+    assert!((model.delta_t(finals.end()) - (32.184 + 37.0 + 0.1782)).abs() < 0.00001);
+
+    let gap = [finals_line(58849.0, -0.1771), finals_line(58851.0, -0.1782)].join("\n");
+    assert_eq!(
+        Table::parse_finals(&gap, &leap_secs).unwrap_err(),
+        Error::InvalidTable { line: 2 }
+    );
+
+}
+
+#[test]
+fn calendars() {
+
+    use time::calendar::*;
+    use time::CalType::{Gregorian, Julian};
+    use time::Month::*;
+
+    assert_eq!(easter(1991, &Gregorian), (Mar, 31));
+    assert_eq!(easter(1992, &Gregorian), (Apr, 19));
+    assert_eq!(easter(2000, &Gregorian), (Apr, 23));
+    assert_eq!(easter(1818, &Gregorian), (Mar, 22));
+    assert_eq!(easter(179, &Julian), (Apr, 12));
+    assert_eq!(easter(2024, &Julian), (Apr, 22));
+
+    // The Julian dates repeat every 532 years, also before the year 1
+    assert_eq!(easter(179 - 532, &Julian), (Apr, 12));
+    for &year in [-1, -100, -1234].iter() {
+        let (month, day) = easter(year, &Gregorian);
+        let sunday = time::Date { year, month, decimal_day: day as f64, cal_type: Gregorian };
+        match time::weekday_frm_date(&sunday) {
+            time::Weekday::Sunday => {},
+            _ => panic!("time::calendar::easter failed for the year {}", year)
+        }
+        let march_day = if month == Mar { day } else { day + 31 };
+        assert!(month == Mar || month == Apr);
+        assert!(march_day >= 22 && march_day <= 56);
+    }
+
+    let date = |year, month, day| time::Date {
+        year        : year,
+        month       : month,
+        decimal_day : day,
+        cal_type    : Gregorian
+    };
+
+    assert_eq!(day_of_year(&date(1978, Nov, 14.0)), 318);
+    assert_eq!(day_of_year(&date(1988, Apr, 22.0)), 113);
+    assert_eq!(date_frm_day_of_year(1988, 113, &Gregorian), (Apr, 22));
+
+    assert_eq!(iso_week(&date(2021, Jan, 3.0)), (2020, 53));
+    assert_eq!(iso_week(&date(2008, Dec, 29.0)), (2009, 1));
+    // The last day of the Julian calendar in Rome, a Thursday
+    let julian_date = time::Date { year: 1582, month: Oct, decimal_day: 4.0, cal_type: Julian };
+    assert_eq!(iso_week(&julian_date), (1582, 40));
+
+    assert_eq!(passover(1990, &Gregorian), (Apr, 10));
+    assert_eq!(rosh_hashanah(2024, &Gregorian), (Oct, 3));
+
+    let JD = time::julian_day(&date(2024, Apr, 23.0));
+    let jewish = JewishDate { year: 5784, month: JewishMonth::Nisan, day: 15 };
+    assert_eq!(jewish_frm_julian_day(JD), jewish);
+    assert_eq!(julian_day_frm_jewish(&jewish), Ok(JD));
+    assert!(is_jewish_leap_year(5784));
+    assert_eq!(
+        julian_day_frm_jewish(&JewishDate { year: 5785, month: JewishMonth::AdarI, day: 1 }),
+        Err(Error::InvalidDate)
+    );
+
+    let JD = time::julian_day(&date(2023, July, 19.0));
+    let islamic = IslamicDate { year: 1445, month: 1, day: 1 };
+    assert_eq!(islamic_frm_julian_day(JD), islamic);
+    assert_eq!(julian_day_frm_islamic(&islamic), Ok(JD));
+    assert_eq!(islamic_frm_julian_day(1948439.5), IslamicDate { year: 1, month: 1, day: 1 });
+
 }
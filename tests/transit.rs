@@ -94,3 +94,176 @@ fn time() {
     assert_eq!((h_set, m_set), (2, 54));
 
 }
+
+#[test]
+fn rise_transit_set() {
+
+    let minutes = |JD: f64| {
+        let day = JD + 0.5;
+        ((day - day.floor()) * 1440.0).round()
+    };
+
+    // Test taken from Meeus 2nd ed. on pages 103-104
+
+    let boston = coords::GeographPoint {
+        long: 71.0833_f64.to_radians(),
+        lat: 42.3333_f64.to_radians(),
+    };
+    let date = time::Date {
+        year        : 1988,
+        month       : time::Month::Mar,
+        decimal_day : 20.0,
+        cal_type    : time::CalType::Gregorian
+    };
+    let venus = transit::Body::Planet(planet::Planet::Venus);
+
+    match transit::rise_transit_set(
+        &venus, &boston, &date, transit::std_alt(&venus, 2447240.5), 56.0
+    ) {
+        transit::RiseTransitSet::RisesAndSets { rise, transit, set } => {
+            assert_eq!(minutes(rise), (12*60 + 25) as f64);
+            assert_eq!(minutes(transit), (19*60 + 41) as f64);
+            assert_eq!(minutes(set), (2*60 + 55) as f64);
+        },
+        _ => panic!("transit::rise_transit_set() failed for Venus")
+    }
+
+    let polar_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 80_f64.to_radians(),
+    };
+    let mut date = time::Date {
+        year        : 2020,
+        month       : time::Month::June,
+        decimal_day : 21.0,
+        cal_type    : time::CalType::Gregorian
+    };
+    let sun = transit::Body::Sun;
+    let h0 = transit::std_alt(&sun, 2459021.5);
+
+    match transit::rise_transit_set(&sun, &polar_point, &date, h0, 69.0) {
+        transit::RiseTransitSet::Circumpolar { .. } => {},
+        _ => panic!("transit::rise_transit_set() failed for the polar day")
+    }
+
+    date.month = time::Month::Dec;
+    match transit::rise_transit_set(&sun, &polar_point, &date, h0, 69.0) {
+        transit::RiseTransitSet::NeverRises { .. } => {},
+        _ => panic!("transit::rise_transit_set() failed for the polar night")
+    }
+
+}
+
+#[test]
+fn twilight() {
+
+    let minutes = |JD: f64| {
+        let day = JD + 0.5;
+        ((day - day.floor()) * 1440.0).round()
+    };
+
+    let london = coords::GeographPoint {
+        long: 0.1278_f64.to_radians(),
+        lat: 51.5074_f64.to_radians(),
+    };
+    let polar_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 80_f64.to_radians(),
+    };
+    let mut date = time::Date {
+        year        : 2020,
+        month       : time::Month::Dec,
+        decimal_day : 21.0,
+        cal_type    : time::CalType::Gregorian
+    };
+
+    match transit::twilight(&transit::Twilight::Civil, &london, &date, 69.0) {
+        transit::TwilightTimes::DawnAndDusk { dawn, dusk } => {
+            assert_eq!(minutes(dawn), (7*60 + 24) as f64);
+            assert_eq!(minutes(dusk), (16*60 + 34) as f64);
+        },
+        _ => panic!("transit::twilight() failed for London")
+    }
+
+    match transit::twilight(&transit::Twilight::Nautical, &polar_point, &date, 69.0) {
+        transit::TwilightTimes::AlwaysBelow => {},
+        _ => panic!("transit::twilight() failed for the polar night")
+    }
+    match transit::twilight(&transit::Twilight::Astronomical, &polar_point, &date, 69.0) {
+        transit::TwilightTimes::DawnAndDusk { .. } => {},
+        _ => panic!("transit::twilight() failed for the polar night")
+    }
+
+    match transit::darkness(
+        &transit::Twilight::Custom(15_f64.to_radians()), &london, &date, 69.0
+    ) {
+        transit::Darkness::BeginsAndEnds { begin, end } => assert!(end - begin > 0.5),
+        _ => panic!("transit::darkness() failed for a custom depression")
+    }
+
+    date.month = time::Month::June;
+    match transit::twilight(&transit::Twilight::Astronomical, &london, &date, 69.0) {
+        transit::TwilightTimes::AlwaysAbove => {},
+        _ => panic!("transit::twilight() failed for the white night")
+    }
+    match transit::darkness(&transit::Twilight::Astronomical, &london, &date, 69.0) {
+        transit::Darkness::NoDarkness => {},
+        _ => panic!("transit::darkness() failed for the white night")
+    }
+
+    match transit::darkness(&transit::Twilight::Nautical, &london, &date, 69.0) {
+        transit::Darkness::BeginsAndEnds { begin, end } => {
+            assert_eq!(minutes(begin), (22*60 + 24) as f64);
+            assert_eq!(minutes(end), (60 + 41) as f64);
+        },
+        _ => panic!("transit::darkness() failed for London")
+    }
+
+    // At 60° N, the last astronomically dark night of spring in 2020
+    // begins on April 20 and ends after midnight, although the Sun stays
+    // above -18° all of April 21
+    let northern_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 60_f64.to_radians(),
+    };
+    date.month = time::Month::Apr;
+    date.decimal_day = 19.0;
+    match transit::darkness(&transit::Twilight::Astronomical, &northern_point, &date, 69.0) {
+        transit::Darkness::BeginsAndEnds { begin, end } => assert!(end > begin),
+        _ => panic!("transit::darkness() failed at 60° N")
+    }
+    date.decimal_day = 20.0;
+    match transit::darkness(&transit::Twilight::Astronomical, &northern_point, &date, 69.0) {
+        transit::Darkness::BeginsAndEnds { begin, end } => {
+            assert_eq!(minutes(begin), (23*60 + 44) as f64);
+            assert_eq!(minutes(end), 13.0);
+        },
+        _ => panic!("transit::darkness() failed for the last dark night at 60° N")
+    }
+    date.decimal_day = 21.0;
+    match transit::darkness(&transit::Twilight::Astronomical, &northern_point, &date, 69.0) {
+        transit::Darkness::NoDarkness => {},
+        _ => panic!("transit::darkness() failed for the first white night at 60° N")
+    }
+
+    // At 59.9° N, the Sun stays above -18° all of 2020 August 20, but
+    // the first astronomically dark night of autumn follows it
+    let northern_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 59.9_f64.to_radians(),
+    };
+    date.month = time::Month::Aug;
+    date.decimal_day = 20.0;
+    match transit::twilight(&transit::Twilight::Astronomical, &northern_point, &date, 69.0) {
+        transit::TwilightTimes::AlwaysAbove => {},
+        _ => panic!("transit::twilight() failed at 59.9° N")
+    }
+    match transit::darkness(&transit::Twilight::Astronomical, &northern_point, &date, 69.0) {
+        transit::Darkness::BeginsAndEnds { begin, end } => {
+            assert_eq!(minutes(begin), (23*60 + 47) as f64);
+            assert_eq!(minutes(end), 20.0);
+        },
+        _ => panic!("transit::darkness() failed for the first dark night at 59.9° N")
+    }
+
+}